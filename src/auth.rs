@@ -0,0 +1,269 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::SlackApiError;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use crate::slack_client::block_on_current_thread;
+use crate::slack_client::decode_response;
+use crate::SlackClient;
+
+/// Timeout of each request of `ping`, short enough for a readiness probe.
+const PING_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Response of the auth.test API method.
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct AuthTest {
+    /// ID of the user the token belongs to.
+    pub user_id: String,
+    /// ID of the team.
+    pub team_id: String,
+    /// Name of the team.
+    pub team: String,
+    /// URL of the team, e.g. `https://example.slack.com/`.
+    pub url: String,
+    /// ID of the bot, if the token is a bot token.
+    #[serde(default)]
+    pub bot_id: Option<String>,
+}
+
+/// Auth trait for the Slack API client.
+pub trait Auth: Send + Sync {
+    /// Checks the token and returns who it belongs to.
+    ///
+    /// Returns `SlackApiError::InvalidAuth` if the token is invalid.
+    ///
+    /// <https://api.slack.com/methods/auth.test>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn test(&self) -> Result<AuthTest, SlackApiError> {
+        block_on_current_thread(self.test_async())
+    }
+
+    /// Checks the token and returns who it belongs to asynchronously.
+    ///
+    /// <https://api.slack.com/methods/auth.test>
+    fn test_async(&self) -> Pin<Box<dyn Future<Output=Result<AuthTest, SlackApiError>> + Send + '_>>;
+
+    /// Checks that Slack is reachable and the token is valid, e.g. for a readiness probe.
+    ///
+    /// Calls api.test then auth.test, each timing out after 5 seconds regardless of the timeout of the client.
+    ///
+    /// <https://api.slack.com/methods/api.test>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn ping(&self) -> Result<(), SlackApiError> {
+        block_on_current_thread(self.ping_async())
+    }
+
+    /// Checks that Slack is reachable and the token is valid asynchronously.
+    ///
+    /// <https://api.slack.com/methods/api.test>
+    fn ping_async(&self) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>>;
+
+    /// Returns the OAuth scopes granted to the token, read from the `x-oauth-scopes` header of auth.test.
+    ///
+    /// Useful to check the scopes an app needs at startup and report the missing ones clearly. Returns
+    /// `SlackApiError::UnexpectedResponse` if the header is missing, e.g. because a proxy stripped it.
+    ///
+    /// <https://api.slack.com/methods/auth.test>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn auth_scopes(&self) -> Result<Vec<String>, SlackApiError> {
+        block_on_current_thread(self.auth_scopes_async())
+    }
+
+    /// Returns the OAuth scopes granted to the token asynchronously.
+    ///
+    /// <https://api.slack.com/methods/auth.test>
+    fn auth_scopes_async(&self) -> Pin<Box<dyn Future<Output=Result<Vec<String>, SlackApiError>> + Send + '_>>;
+}
+
+/// Implement the Auth trait for SlackClient.
+impl Auth for SlackClient {
+    /// Checks the token.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn test(&self) -> Result<AuthTest, SlackApiError> {
+        self.block_on(self.test_async())
+    }
+
+    /// Checks the token asynchronously.
+    fn test_async(&self) -> Pin<Box<dyn Future<Output=Result<AuthTest, SlackApiError>> + Send + '_>> {
+        Box::pin(async move {
+            let body = self.post_form("auth.test", &()).await?;
+            decode_response(body)
+        })
+    }
+
+    /// Checks connectivity and the token.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn ping(&self) -> Result<(), SlackApiError> {
+        self.block_on(self.ping_async())
+    }
+
+    /// Checks connectivity and the token asynchronously.
+    fn ping_async(&self) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        Box::pin(async move {
+            self.post_form_with_timeout("api.test", &(), PING_TIMEOUT).await?;
+            self.post_form_with_timeout("auth.test", &(), PING_TIMEOUT).await?;
+            Ok(())
+        })
+    }
+
+    /// Returns the scopes of the token.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn auth_scopes(&self) -> Result<Vec<String>, SlackApiError> {
+        self.block_on(self.auth_scopes_async())
+    }
+
+    /// Returns the scopes of the token asynchronously.
+    fn auth_scopes_async(&self) -> Pin<Box<dyn Future<Output=Result<Vec<String>, SlackApiError>> + Send + '_>> {
+        Box::pin(async move {
+            let (_, headers) = self.post_form_with_headers("auth.test", &()).await?;
+
+            // The header is a comma-separated list, e.g. `chat:write,channels:read`
+            let scopes = headers.get("x-oauth-scopes").ok_or_else(|| SlackApiError::UnexpectedResponse {
+                method: "auth.test".to_string(),
+                status: 200,
+                snippet: "No x-oauth-scopes header in response".to_string(),
+            })?;
+            Ok(scopes.split(',').map(str::trim).filter(|scope| !scope.is_empty()).map(String::from).collect())
+        })
+    }
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod auth_tests {
+    use std::env;
+
+    use serde_json::json;
+    use tokio::runtime::Runtime;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn auth_test() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Check the token
+        let test = client.test();
+        assert!(test.is_ok(), "Failed to check token");
+        assert!(!test.unwrap().user_id.is_empty(), "Missing user ID");
+    }
+
+    #[test]
+    fn auth_test_invalid_token() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/auth.test"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "invalid_auth" })))
+                .mount(&server)
+        );
+
+        // Check an invalid token
+        let client = SlackClient::with_base_url("xoxb-invalid".to_string(), server.uri());
+        let test = client.test();
+        assert!(matches!(test, Err(SlackApiError::InvalidAuth)), "Expected an invalid auth error");
+    }
+
+    #[test]
+    fn auth_ping() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/api.test"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })))
+                .expect(2)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/auth.test"))
+                .and(header("authorization", "Bearer xoxb-test"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true, "user_id": "U0000000000", "team_id": "T0000000000", "team": "Example", "url": "https://example.slack.com/" })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/auth.test"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "invalid_auth" })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        assert_eq!(client.ping(), Ok(()));
+
+        // Slack is reachable but the token is not valid
+        let client = SlackClient::with_base_url("xoxb-revoked".to_string(), server.uri());
+        assert_eq!(client.ping(), Err(SlackApiError::InvalidAuth));
+    }
+
+    #[test]
+    fn auth_scopes() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/auth.test"))
+                .respond_with(ResponseTemplate::new(200)
+                    .insert_header("x-oauth-scopes", "chat:write, channels:read,users:read")
+                    .set_body_json(json!({ "ok": true, "user_id": "U0000000000", "team_id": "T0000000000", "team": "Example", "url": "https://example.slack.com/" })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let scopes = client.auth_scopes().unwrap();
+        assert_eq!(scopes, vec!["chat:write", "channels:read", "users:read"]);
+    }
+
+    #[test]
+    fn auth_scopes_without_header() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/auth.test"))
+                .respond_with(ResponseTemplate::new(200)
+                    .set_body_json(json!({ "ok": true, "user_id": "U0000000000", "team_id": "T0000000000", "team": "Example", "url": "https://example.slack.com/" })))
+                .mount(&server)
+        );
+
+        // A response without the header, e.g. behind a proxy stripping it, is not the caller's mistake
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let scopes = client.auth_scopes();
+        assert!(matches!(scopes, Err(SlackApiError::UnexpectedResponse { ref method, .. }) if method == "auth.test"), "Expected an unexpected response error");
+    }
+
+    /// Auth implemented with the async methods only, as a crate without the `blocking` feature would.
+    struct FixedAuth;
+
+    impl Auth for FixedAuth {
+        fn test_async(&self) -> Pin<Box<dyn Future<Output=Result<AuthTest, SlackApiError>> + Send + '_>> {
+            Box::pin(async { Ok(AuthTest { user_id: "U0000000000".to_string(), ..AuthTest::default() }) })
+        }
+
+        fn ping_async(&self) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+            Box::pin(async { Ok(()) })
+        }
+
+        fn auth_scopes_async(&self) -> Pin<Box<dyn Future<Output=Result<Vec<String>, SlackApiError>> + Send + '_>> {
+            Box::pin(async { Ok(vec!["chat:write".to_string()]) })
+        }
+    }
+
+    #[test]
+    fn auth_blocking_methods_have_defaults() {
+        // The blocking methods run the async ones, so enabling the feature does not break the implementation
+        let auth = FixedAuth;
+        assert_eq!(auth.test().unwrap().user_id, "U0000000000");
+        assert_eq!(auth.ping(), Ok(()));
+        assert_eq!(auth.auth_scopes(), Ok(vec!["chat:write".to_string()]));
+    }
+}
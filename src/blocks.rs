@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Text object used inside Block Kit blocks.
+///
+/// <https://api.slack.com/reference/block-kit/composition-objects#text>
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Text {
+    /// Plain text, rendered as-is.
+    PlainText {
+        text: String,
+        /// Whether emojis in the text should be escaped into the colon emoji format.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    /// Text formatted with Slack's mrkdwn markup.
+    Mrkdwn {
+        text: String,
+        /// When true, URLs and mentions are not automatically linked.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+impl Text {
+    /// Create a plain text object.
+    pub fn plain_text(text: impl Into<String>) -> Self {
+        Text::PlainText { text: text.into(), emoji: None }
+    }
+
+    /// Create a mrkdwn text object.
+    pub fn mrkdwn(text: impl Into<String>) -> Self {
+        Text::Mrkdwn { text: text.into(), verbatim: None }
+    }
+}
+
+/// Layout block of a message.
+///
+/// <https://api.slack.com/reference/block-kit/blocks>
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    /// A section of text, optionally with fields shown in two columns.
+    Section {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<Text>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fields: Option<Vec<Text>>,
+    },
+    /// A visual separator between blocks.
+    Divider,
+    /// A larger, bold heading. The text must be plain text.
+    Header {
+        text: Text,
+    },
+    /// Small, muted text shown in a row.
+    Context {
+        elements: Vec<Text>,
+    },
+    /// A row of interactive elements such as buttons, given as raw Block Kit JSON.
+    Actions {
+        elements: Vec<Value>,
+    },
+}
+
+impl Block {
+    /// Create a section block with text.
+    pub fn section(text: Text) -> Self {
+        Block::Section { text: Some(text), fields: None }
+    }
+
+    /// Create a section block with fields.
+    pub fn section_fields(fields: Vec<Text>) -> Self {
+        Block::Section { text: None, fields: Some(fields) }
+    }
+
+    /// Create a divider block.
+    pub fn divider() -> Self {
+        Block::Divider
+    }
+
+    /// Create a header block with plain text.
+    pub fn header(text: impl Into<String>) -> Self {
+        Block::Header { text: Text::plain_text(text) }
+    }
+
+    /// Create a context block.
+    pub fn context(elements: Vec<Text>) -> Self {
+        Block::Context { elements }
+    }
+
+    /// Create an actions block.
+    pub fn actions(elements: Vec<Value>) -> Self {
+        Block::Actions { elements }
+    }
+}
+
+/// Escapes the control characters `&`, `<`, and `>` so user input is shown as-is in mrkdwn text.
+///
+/// Slack treats `<...>` as links and mentions, so unescaped user input can break rendering.
+/// This includes text inside code blocks, where mentions such as `<!here>` still notify; the entities are shown
+/// there as the characters they stand for.
+///
+/// <https://api.slack.com/reference/surfaces/formatting#escaping>
+pub fn escape_mrkdwn(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+impl From<Block> for Value {
+    fn from(block: Block) -> Self {
+        serde_json::to_value(block).expect("Block always serializes to JSON")
+    }
+}
+
+
+#[cfg(test)]
+mod blocks_tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn blocks_serialize_to_slack_json() {
+        let blocks: Vec<Value> = vec![
+            Block::header("Deploy finished"),
+            Block::section(Text::mrkdwn("*hi*")),
+            Block::divider(),
+            Block::context(vec![Text::plain_text("rustslack")]),
+        ].into_iter().map(Value::from).collect();
+
+        assert_eq!(blocks, vec![
+            json!({ "type": "header", "text": { "type": "plain_text", "text": "Deploy finished" } }),
+            json!({ "type": "section", "text": { "type": "mrkdwn", "text": "*hi*" } }),
+            json!({ "type": "divider" }),
+            json!({ "type": "context", "elements": [{ "type": "plain_text", "text": "rustslack" }] }),
+        ]);
+    }
+
+    #[test]
+    fn blocks_escape_mrkdwn() {
+        assert_eq!(escape_mrkdwn("a < b && c > d"), "a &lt; b &amp;&amp; c &gt; d");
+        assert_eq!(escape_mrkdwn("<!channel> &lt;"), "&lt;!channel&gt; &amp;lt;");
+        assert_eq!(escape_mrkdwn("*bold*"), "*bold*");
+    }
+
+    #[test]
+    fn blocks_section_fields() {
+        let block = Value::from(Block::section_fields(vec![Text::mrkdwn("*Status*"), Text::mrkdwn("OK")]));
+
+        assert_eq!(block, json!({
+            "type": "section",
+            "fields": [{ "type": "mrkdwn", "text": "*Status*" }, { "type": "mrkdwn", "text": "OK" }]
+        }));
+    }
+}
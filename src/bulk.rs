@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use futures::stream::{Stream, StreamExt};
+use tokio::time::Instant;
+
+use crate::chat::{Chat, ChatPostMessageArguments};
+use crate::errors::SlackApiError;
+use crate::SlackClient;
+
+/// Posts many messages in order, pacing them per channel to stay under Slack's limit of about one message
+/// per second per channel.
+///
+/// Each channel has a token bucket holding up to `burst` messages and refilled with one message every
+/// `interval`. A message to a channel with an empty bucket waits for the next token, which also holds back
+/// the messages after it so results keep the order of the input.
+///
+/// Not available on wasm32, which has no clock or timer to pace the messages with.
+pub struct BulkPoster {
+    client: SlackClient,
+    burst: u32,
+    interval: Duration,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+/// Token bucket of a channel.
+struct Bucket {
+    /// Messages that can be posted right away. Negative when messages are waiting for tokens.
+    tokens: f64,
+    /// When the tokens were last refilled.
+    refilled_at: Instant,
+}
+
+impl BulkPoster {
+    /// Create a new bulk poster posting at most one message per second to each channel.
+    pub fn new(client: SlackClient) -> Self {
+        BulkPoster::with_rate(client, 1, Duration::from_secs(1))
+    }
+
+    /// Create a new bulk poster posting up to `burst` messages at once to a channel, then one every `interval`.
+    pub fn with_rate(client: SlackClient, burst: u32, interval: Duration) -> Self {
+        BulkPoster {
+            client,
+            burst: burst.max(1),
+            interval,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Posts each message to its channel in order, returning the ts or error of each message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    #[allow(clippy::type_complexity)]
+    pub fn post_all(&self, messages: Vec<(String, ChatPostMessageArguments)>) -> Result<Vec<Result<String, SlackApiError>>, SlackApiError> {
+        self.client.block_on(async { Ok(self.post_stream(futures::stream::iter(messages)).collect().await) })
+    }
+
+    /// Posts each message of a stream to its channel, returning a stream of the ts or error of each message in order.
+    pub fn post_stream<'a>(&'a self, messages: impl Stream<Item=(String, ChatPostMessageArguments)> + Send + 'a) -> Pin<Box<dyn Stream<Item=Result<String, SlackApiError>> + Send + 'a>> {
+        Box::pin(messages.then(move |(channel, arguments)| async move {
+            // Wait for a token of the channel before posting
+            let wait = self.reserve(&channel);
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+
+            self.client.post_message_async(ChatPostMessageArguments { channel, ..arguments }).await
+        }))
+    }
+
+    /// Take a token from the bucket of `channel`, returning how long to wait until it is available.
+    fn reserve(&self, channel: &str) -> Duration {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(channel.to_string()).or_insert(Bucket { tokens: self.burst as f64, refilled_at: now });
+
+        // Refill the tokens for the time since the last message, up to the burst
+        if !self.interval.is_zero() {
+            let refilled = now.duration_since(bucket.refilled_at).as_secs_f64() / self.interval.as_secs_f64();
+            bucket.tokens = (bucket.tokens + refilled).min(self.burst as f64);
+        } else {
+            bucket.tokens = self.burst as f64;
+        }
+        bucket.refilled_at = now;
+
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            self.interval.mul_f64(-bucket.tokens)
+        }
+    }
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod bulk_tests {
+    use std::time;
+
+    use serde_json::json;
+    use tokio::runtime::Runtime;
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn bulk_post_all_paces_each_channel() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        for (channel, ts) in [("C0000000001", "1700000000.000100"), ("C0000000002", "1700000000.000200")] {
+            runtime.block_on(
+                Mock::given(method("POST"))
+                    .and(path("/chat.postMessage"))
+                    .and(body_string_contains(channel))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true, "channel": channel, "ts": ts })))
+                    .mount(&server)
+            );
+        }
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let poster = BulkPoster::with_rate(client, 1, Duration::from_millis(300));
+        let message = |channel: &str| (channel.to_string(), ChatPostMessageArguments::builder("").text("Backfill").build());
+
+        // The second message to the first channel waits for a token, and results keep the input order
+        let started = time::Instant::now();
+        let results = poster.post_all(vec![message("C0000000001"), message("C0000000002"), message("C0000000001")]).unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(300), "Expected the messages to be paced");
+        assert_eq!(results, vec![
+            Ok("1700000000.000100".to_string()),
+            Ok("1700000000.000200".to_string()),
+            Ok("1700000000.000100".to_string()),
+        ]);
+    }
+}
@@ -1,18 +1,52 @@
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
 
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use futures::future::{self, join_all, Either};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{json, Value};
+use tokio_util::sync::CancellationToken;
+#[cfg(feature = "chrono-tz")]
+use chrono::{LocalResult, NaiveDateTime, TimeZone};
+#[cfg(feature = "chrono-tz")]
+use chrono_tz::Tz;
 
+use crate::blocks::{escape_mrkdwn, Block};
 use crate::errors::SlackApiError;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use crate::slack_client::block_on_current_thread;
+use crate::slack_client::{decode_response, in_channel, next_cursor, unix_now};
+use crate::ts::Ts;
 use crate::SlackClient;
 
+/// Maximum number of chat.delete requests in flight at once for delete_many.
+const DELETE_CONCURRENCY: usize = 5;
+
+/// Maximum number of characters of message text. Slack truncates longer messages.
+pub const MAX_TEXT_LEN: usize = 40_000;
+
+/// Maximum number of blocks Slack accepts in a single message.
+const MAX_BLOCKS: usize = 50;
+/// Maximum number of attachments Slack accepts in a single message.
+const MAX_ATTACHMENTS: usize = 20;
+
+/// Maximum number of characters of the title and the language of a snippet, so the header always leaves room for
+/// the content within MAX_TEXT_LEN.
+const MAX_SNIPPET_TITLE_LEN: usize = 200;
+
+/// Furthest ahead Slack accepts a scheduled message, in seconds (120 days).
+const MAX_SCHEDULE_AHEAD_SECS: i64 = 120 * 24 * 60 * 60;
+/// How far in the past `post_at` may be, in seconds, to allow for clock skew with Slack.
+const SCHEDULE_SKEW_SECS: i64 = 60;
+
 /// Arguments for the chat.postMessage API method.
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
 pub struct ChatPostMessageArguments {
     /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name.
     pub channel: String,
     /// Text of the message to send. This field is usually required, unless you're providing only `attachments` or `blocks`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
     /// Blocks of the message to send. This field is usually required, unless you're providing only `text` or `attachments`.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -20,7 +54,7 @@ pub struct ChatPostMessageArguments {
     /// A JSON-based array of structured attachments, presented as a URL-encoded string.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attachments: Option<Vec<ChatPostMessageAttachment>>,
-    /// Emoji to use as the icon for this message. Overrides icon_url.
+    /// Emoji to use as the icon for this message. Overrides icon_url. A bare name like `rocket` is sent as `:rocket:`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_emoji: Option<String>,
     /// URL to an image to use as the icon for this message.
@@ -29,28 +63,186 @@ pub struct ChatPostMessageArguments {
     /// Find and link user groups. No longer supports linking individual users
     #[serde(skip_serializing_if = "Option::is_none")]
     pub link_names: Option<bool>,
-    /// JSON object with event_type and event_payload fields, presented as a URL-encoded string. Metadata you post to Slack is accessible to any app or user who is a member of that workspace.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<Vec<serde_json::Value>>,
+    /// Event metadata of the message. Metadata you post to Slack is accessible to any app or user who is a member of that workspace.
+    ///
+    /// The array this field used to hold is still read, taking its first element; use `MessageMetadata::from_values` to migrate.
+    #[serde(default, deserialize_with = "deserialize_metadata", skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<MessageMetadata>,
     /// Disable Slack markup parsing by setting to false. Enabled by default.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mrkdwn: Option<bool>,
-    /// Change how messages are treated.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse: Option<String>,
+    /// Change how messages are treated. Unknown values are read as `None`.
+    #[serde(default, deserialize_with = "deserialize_parse", skip_serializing_if = "Option::is_none")]
+    pub parse: Option<Parse>,
     /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_broadcast: Option<bool>,
     /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_ts: Option<String>,
+    /// Pass true to enable unfurling of primarily text-based content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    /// Pass false to disable unfurling of media content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
     /// Set your bot's user name.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
+impl ChatPostMessageArguments {
+    /// Create a builder for the arguments, posting to `channel`.
+    pub fn builder(channel: impl Into<String>) -> ChatPostMessageArgumentsBuilder {
+        ChatPostMessageArgumentsBuilder {
+            arguments: ChatPostMessageArguments {
+                channel: channel.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// How Slack treats the text of a message, for the `parse` field.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Parse {
+    /// Link channel names and user names, and ignore the markup of the text.
+    Full,
+    /// Leave the text as is.
+    None,
+}
+
+/// Read the `parse` field case-insensitively, ignoring values other than `full` and `none`.
+fn deserialize_parse<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Parse>, D::Error> {
+    let parse: Option<String> = Option::deserialize(deserializer)?;
+    match parse.map(|parse| parse.to_ascii_lowercase()).as_deref() {
+        Some("full") => Ok(Some(Parse::Full)),
+        Some("none") => Ok(Some(Parse::None)),
+        _ => Ok(None),
+    }
+}
+
+/// Event metadata attached to a message, e.g. for other apps to react to it.
+///
+/// <https://api.slack.com/metadata/using>
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+pub struct MessageMetadata {
+    /// Name of the event, e.g. `deploy_finished`.
+    pub event_type: String,
+    /// Data of the event, a JSON object.
+    #[serde(default)]
+    pub event_payload: Value,
+}
+
+impl MessageMetadata {
+    /// Create message metadata for the event `event_type` with `event_payload`.
+    pub fn new(event_type: impl Into<String>, event_payload: Value) -> Self {
+        MessageMetadata { event_type: event_type.into(), event_payload }
+    }
+
+    /// Convert the array formerly held by `ChatPostMessageArguments::metadata`, which Slack rejects, into metadata.
+    ///
+    /// Only the first element is kept, Slack accepts a single metadata object per message.
+    #[deprecated(note = "build the metadata with `MessageMetadata::new` instead")]
+    pub fn from_values(values: Vec<Value>) -> Option<Self> {
+        values.into_iter().next().and_then(|value| serde_json::from_value(value).ok())
+    }
+}
+
+/// Read the `metadata` field as an object, or as the first element of the array it used to be.
+fn deserialize_metadata<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<MessageMetadata>, D::Error> {
+    match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::Array(values)) => match values.into_iter().next() {
+            Some(value) => serde_json::from_value(value).map(Some).map_err(serde::de::Error::custom),
+            None => Ok(None),
+        },
+        Some(Value::Null) | None => Ok(None),
+        Some(value) => serde_json::from_value(value).map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+/// Builder for ChatPostMessageArguments.
+#[derive(Default, Debug)]
+pub struct ChatPostMessageArgumentsBuilder {
+    arguments: ChatPostMessageArguments,
+}
+
+impl ChatPostMessageArgumentsBuilder {
+    /// Set the text of the message.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.arguments.text = Some(text.into());
+        self
+    }
+
+    /// Set the ts of the parent message to reply to.
+    pub fn thread_ts(mut self, thread_ts: impl Into<String>) -> Self {
+        self.arguments.thread_ts = Some(thread_ts.into());
+        self
+    }
+
+    /// Set the emoji to use as the icon for the message, e.g. `rocket` or `:rocket:`.
+    pub fn icon_emoji(mut self, icon_emoji: impl Into<String>) -> Self {
+        self.arguments.icon_emoji = Some(icon_emoji.into());
+        self
+    }
+
+    /// Set the bot's user name.
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.arguments.username = Some(username.into());
+        self
+    }
+
+    /// Set how Slack treats the text of the message.
+    pub fn parse(mut self, parse: Parse) -> Self {
+        self.arguments.parse = Some(parse);
+        self
+    }
+
+    /// Set whether text-based links in the message are unfurled.
+    pub fn unfurl_links(mut self, unfurl_links: bool) -> Self {
+        self.arguments.unfurl_links = Some(unfurl_links);
+        self
+    }
+
+    /// Set whether media links in the message are unfurled.
+    pub fn unfurl_media(mut self, unfurl_media: bool) -> Self {
+        self.arguments.unfurl_media = Some(unfurl_media);
+        self
+    }
+
+    /// Set the blocks of the message.
+    pub fn blocks(mut self, blocks: Vec<Value>) -> Self {
+        self.arguments.blocks = Some(blocks);
+        self
+    }
+
+    /// Set the blocks of the message from typed Block Kit blocks.
+    pub fn blocks_typed(mut self, blocks: Vec<Block>) -> Self {
+        self.arguments.blocks = Some(blocks.into_iter().map(Value::from).collect());
+        self
+    }
+
+    /// Set the attachments of the message.
+    pub fn attachments(mut self, attachments: Vec<ChatPostMessageAttachment>) -> Self {
+        self.arguments.attachments = Some(attachments);
+        self
+    }
+
+    /// Set the event metadata of the message.
+    pub fn metadata(mut self, metadata: MessageMetadata) -> Self {
+        self.arguments.metadata = Some(metadata);
+        self
+    }
+
+    /// Build the arguments. No validation is done here; it happens when the message is posted.
+    pub fn build(self) -> ChatPostMessageArguments {
+        self.arguments
+    }
+}
+
 /// Attachment to a message.
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
 pub struct ChatPostMessageAttachment {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fallback: Option<String>,
@@ -82,172 +274,2241 @@ pub struct ChatPostMessageAttachment {
     pub footer_icon: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrkdwn_in: Option<Vec<String>>,
+}
+
+impl ChatPostMessageAttachment {
+    /// Create an attachment with the green bar Slack uses for success (`good`).
+    pub fn good() -> Self {
+        ChatPostMessageAttachment::with_color("good")
+    }
+
+    /// Create an attachment with the yellow bar Slack uses for warnings (`warning`).
+    pub fn warning() -> Self {
+        ChatPostMessageAttachment::with_color("warning")
+    }
+
+    /// Create an attachment with the red bar Slack uses for errors (`danger`).
+    pub fn danger() -> Self {
+        ChatPostMessageAttachment::with_color("danger")
+    }
+
+    /// Create an attachment with a bar of `color`, a hex code like `#36a64f` or one of `good`, `warning`, and `danger`.
+    pub fn with_color(color: impl Into<String>) -> Self {
+        ChatPostMessageAttachment {
+            color: Some(color.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the text of the attachment.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct ChatPostMessageField {
     pub title: String,
     pub value: String,
     pub short: bool,
 }
 
+/// Arguments for the chat.update API method.
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+pub struct ChatUpdateArguments {
+    /// Channel containing the message to be updated.
+    pub channel: String,
+    /// Timestamp of the message to be updated.
+    pub ts: String,
+    /// New text for the message. This field is usually required, unless you're providing only `attachments` or `blocks`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// New blocks for the message. This field is usually required, unless you're providing only `text` or `attachments`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<serde_json::Value>>,
+    /// New attachments for the message. This field is usually required, unless you're providing only `text` or `blocks`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<ChatPostMessageAttachment>>,
+}
+
+/// Request body of the chat.scheduleMessage API method.
+#[derive(Serialize)]
+struct ChatScheduleMessageRequest {
+    #[serde(flatten)]
+    arguments: ChatPostMessageArguments,
+    /// Unix timestamp representing the future time the message should post to Slack.
+    post_at: i64,
+}
+
+/// Request body of the chat.postEphemeral API method.
+#[derive(Serialize)]
+struct ChatPostEphemeralRequest {
+    #[serde(flatten)]
+    arguments: ChatPostMessageArguments,
+    /// ID of the user who will receive the ephemeral message.
+    user: String,
+}
+
+/// Response of the chat.postMessage API method.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ChatPostMessageResponse {
+    /// Whether the request was successful.
+    pub ok: bool,
+    /// ID of the channel the message was posted to.
+    pub channel: String,
+    /// Timestamp of the posted message.
+    pub ts: String,
+    /// The message as it was posted.
+    #[serde(default)]
+    pub message: PostedMessage,
+    /// Warning returned alongside a successful response, e.g. `missing_charset`, or several separated by commas.
+    ///
+    /// `deprecated_argument` means the message uses legacy attachment fields that have Block Kit equivalents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    /// Selected HTTP response headers, such as `x-slack-req-id`, `retry-after`, and `x-oauth-scopes`, keyed by lowercase name.
+    #[serde(skip)]
+    pub headers: HashMap<String, String>,
+}
+
+impl ChatPostMessageResponse {
+    /// Whether the posted message is the root of its thread, i.e. not a reply to another message.
+    pub fn is_thread_root(&self) -> bool {
+        self.message.thread_ts.as_ref().is_none_or(|thread_ts| *thread_ts == self.ts)
+    }
+
+    /// Each warning of the response, e.g. `["deprecated_argument", "missing_charset"]`.
+    pub fn warnings(&self) -> Vec<&str> {
+        self.warning.as_deref().map(|warning| warning.split(',').map(str::trim).filter(|warning| !warning.is_empty()).collect()).unwrap_or_default()
+    }
+}
+
+/// A message as echoed back by chat.postMessage. Fields are missing when Slack leaves them out for the kind of message.
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+pub struct PostedMessage {
+    /// Timestamp of the message.
+    #[serde(default)]
+    pub ts: Option<String>,
+    /// Text of the message.
+    #[serde(default)]
+    pub text: Option<String>,
+    /// ID of the user who posted the message.
+    #[serde(default)]
+    pub user: Option<String>,
+    /// ID of the bot that posted the message, useful to recognize the app's own messages in conversations.history.
+    #[serde(default)]
+    pub bot_id: Option<String>,
+    /// ID of the app that posted the message.
+    #[serde(default)]
+    pub app_id: Option<String>,
+    /// User name the message was posted with, if it was overridden.
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Blocks of the message, as parsed by Slack.
+    #[serde(default)]
+    pub blocks: Option<Vec<Value>>,
+    /// Timestamp of the parent message, if the message is a thread reply.
+    #[serde(default)]
+    pub thread_ts: Option<String>,
+}
+
+/// A message scheduled with chat.scheduleMessage that has not been posted yet.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ScheduledMessage {
+    /// ID of the scheduled message.
+    pub id: String,
+    /// ID of the channel the message will be posted to.
+    pub channel_id: String,
+    /// Unix timestamp at which the message will be posted.
+    pub post_at: i64,
+    /// Unix timestamp at which the message was scheduled.
+    pub date_created: i64,
+}
+
+/// A thread started with `post_thread`, to reply to without repeating its channel and ts.
+pub struct ThreadHandle<'a, C: Chat> {
+    chat: &'a C,
+    /// Response of the message at the root of the thread.
+    pub root: ChatPostMessageResponse,
+}
+
+impl<'a, C: Chat> ThreadHandle<'a, C> {
+    /// ID of the channel of the thread.
+    pub fn channel(&self) -> &str {
+        &self.root.channel
+    }
+
+    /// Timestamp of the root message, to reply to.
+    pub fn thread_ts(&self) -> &str {
+        &self.root.ts
+    }
+
+    /// Replies to the thread with text only.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub fn reply(&self, text: impl Into<String>) -> Result<String, SlackApiError> {
+        self.chat.reply_in_thread(self.channel(), self.thread_ts(), text, false)
+    }
+
+    /// Replies to the thread with text only asynchronously.
+    pub fn reply_async(&self, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'a>> {
+        self.chat.reply_in_thread_async(self.channel(), self.thread_ts(), text, false)
+    }
+}
+
 /// Chat trait for the Slack API client.
-pub trait Chat {
+///
+/// The trait can be used as `Arc<dyn Chat>`, e.g. to swap in a fake client in tests. Methods taking
+/// `impl Into<String>` are not available on trait objects; the ones taking argument structs are.
+/// The returned futures borrow the client. For all the methods on a trait object, with `'static` futures,
+/// wrap the client in a `SharedChat` and use it as an `Arc<dyn DynChat>`.
+pub trait Chat: Send + Sync {
     /// Deletes a message from a channel.
     ///
     /// <https://api.slack.com/methods/chat.delete>
-    fn delete(&self, channel: String, ts: String) -> Result<(), SlackApiError>;
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete(&self, channel: impl Into<String>, ts: impl Into<String>) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.delete_async(channel, ts))
+    }
 
     /// Deletes a message from a channel asynchronously.
     ///
     /// <https://api.slack.com/methods/chat.delete>
-    fn delete_async(&self, channel: String, ts: String) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>>;
+    fn delete_async(&self, channel: impl Into<String>, ts: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
 
     /// Sends a message to a channel.
     ///
     /// <https://api.slack.com/methods/chat.postMessage>
-    fn post_message(&self, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError>;
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message(&self, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError> {
+        block_on_current_thread(self.post_message_async(arguments))
+    }
     /// Sends a message to a channel asynchronously.
     ///
     /// <https://api.slack.com/methods/chat.postMessage>
     fn post_message_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>>;
 
+    /// Sends a message to a channel and returns the full response.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_full(&self, arguments: ChatPostMessageArguments) -> Result<ChatPostMessageResponse, SlackApiError> {
+        block_on_current_thread(self.post_message_full_async(arguments))
+    }
+
+    /// Sends a message to a channel and returns the full response asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    fn post_message_full_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<ChatPostMessageResponse, SlackApiError>> + Send + '_>>;
+
     /// Sends a message to a channel with text only.
-    fn post_message_text(&self, channel: String, text: String) -> Result<String, SlackApiError>;
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_text(&self, channel: impl Into<String>, text: impl Into<String>) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.post_message_text_async(channel, text))
+    }
 
     /// Sends a message to a channel with text only asynchronously.
-    fn post_message_text_async(&self, channel: String, text: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>>;
-}
+    fn post_message_text_async(&self, channel: impl Into<String>, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
 
-/// Implement the Chat trait for SlackClient.
-impl Chat for SlackClient {
-    /// Deletes a message from a channel.
-    fn delete(&self, channel: String, ts: String) -> Result<(), SlackApiError> {
-        self.runtime.block_on(self.delete_async(channel, ts))
+    /// Sends a message with text only to the client's default channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_text_default(&self, text: impl Into<String>) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.post_message_text_default_async(text))
     }
 
-    /// Deletes a message from a channel asynchronously.
-    fn delete_async(&self, channel: String, ts: String) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
-        let client = self.client.clone();
-        let token = self.token.clone();
+    /// Sends a message with text only to the client's default channel asynchronously.
+    fn post_message_text_default_async(&self, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
 
-        Box::pin(async move {
-            let res = client.post("https://slack.com/api/chat.delete")
-                .bearer_auth(&token)
-                .form(&[("channel", &channel), ("ts", &ts)])
-                .send()
-                .await
-                .map_err(SlackApiError::from)?
-                .error_for_status()
-                .map_err(SlackApiError::from)?;
-
-            let body: Value = res.json().await.map_err(SlackApiError::from)?;
-            if body["ok"].as_bool().unwrap_or(false) {
-                Ok(())
-            } else {
-                Err(SlackApiError::InvalidArgument("Failed to delete message".into()))
-            }
-        })
+    /// Replies to the thread of `thread_ts` with text only. With `broadcast`, the reply is also shown in the channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn reply_in_thread(&self, channel: impl Into<String>, thread_ts: impl Into<String>, text: impl Into<String>, broadcast: bool) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.reply_in_thread_async(channel, thread_ts, text, broadcast))
     }
 
-    /// Posts a message to a channel.
-    fn post_message(&self, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError> {
-        self.runtime.block_on(self.post_message_async(arguments))
+    /// Replies to the thread of `thread_ts` with text only asynchronously.
+    fn reply_in_thread_async(&self, channel: impl Into<String>, thread_ts: impl Into<String>, text: impl Into<String>, broadcast: bool) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Updates a message in a channel.
+    ///
+    /// <https://api.slack.com/methods/chat.update>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn update(&self, arguments: ChatUpdateArguments) -> Result<String, SlackApiError> {
+        block_on_current_thread(self.update_async(arguments))
     }
 
-    /// Posts a message to a channel asynchronously.
-    fn post_message_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+    /// Updates a message in a channel asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.update>
+    fn update_async(&self, arguments: ChatUpdateArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>>;
 
-        // Check if the text, attachments, or blocks fields are provided
-        if arguments.text.is_none() && arguments.attachments.is_none() && arguments.blocks.is_none() {
-            return Box::pin(async { Err(SlackApiError::InvalidArgument("text, attachments, or blocks is required".into())) });
-        }
+    /// Schedules a message to be sent to a channel at `post_at` (Unix timestamp).
+    ///
+    /// `post_at` must be in the future, and at most 120 days ahead.
+    ///
+    /// <https://api.slack.com/methods/chat.scheduleMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn schedule_message(&self, arguments: ChatPostMessageArguments, post_at: i64) -> Result<String, SlackApiError> {
+        block_on_current_thread(self.schedule_message_async(arguments, post_at))
+    }
 
-        let client = self.client.clone();
-        let token = self.token.clone();
+    /// Schedules a message to be sent to a channel at `post_at` (Unix timestamp) asynchronously.
+    ///
+    /// On wasm32, which has no system clock, `post_at` is only checked by Slack.
+    ///
+    /// <https://api.slack.com/methods/chat.scheduleMessage>
+    fn schedule_message_async(&self, arguments: ChatPostMessageArguments, post_at: i64) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>>;
 
-        // Send the request to the Slack API
-        Box::pin(async move {
-            let res = client.post("https://slack.com/api/chat.postMessage")
-                .bearer_auth(token)
-                .json(&arguments)
-                .send()
-                .await
-                .map_err(SlackApiError::from)?
-                .error_for_status()
-                .map_err(SlackApiError::from)?;
-
-            // Parse the response body as JSON
-            let body: Value = res.json().await.map_err(SlackApiError::from)?;
+    /// Deletes a pending scheduled message from the queue.
+    ///
+    /// <https://api.slack.com/methods/chat.deleteScheduledMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete_scheduled_message(&self, channel: impl Into<String>, scheduled_message_id: impl Into<String>) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.delete_scheduled_message_async(channel, scheduled_message_id))
+    }
 
-            // Extract the message ID from the JSON
-            let message_id = body["message"]["ts"].as_str().ok_or(SlackApiError::InvalidArgument("No message ID in response".into()))?.to_string();
+    /// Deletes a pending scheduled message from the queue asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.deleteScheduledMessage>
+    fn delete_scheduled_message_async(&self, channel: impl Into<String>, scheduled_message_id: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
 
-            Ok(message_id)
-        })
+    /// Retrieves a permalink URL for a message.
+    ///
+    /// Returns `SlackApiError::MessageNotFound` if the message does not exist.
+    ///
+    /// <https://api.slack.com/methods/chat.getPermalink>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn get_permalink(&self, channel: impl Into<String>, message_ts: impl Into<String>) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.get_permalink_async(channel, message_ts))
     }
 
-    /// Sends a message to a channel with text only.
-    fn post_message_text(&self, channel: String, text: String) -> Result<String, SlackApiError> {
-        self.runtime.block_on(self.post_message_text_async(channel, text))
+    /// Retrieves a permalink URL for a message asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.getPermalink>
+    fn get_permalink_async(&self, channel: impl Into<String>, message_ts: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Sends an ephemeral message, visible only to `user`, to a channel.
+    ///
+    /// <https://api.slack.com/methods/chat.postEphemeral>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_ephemeral(&self, arguments: ChatPostMessageArguments, user: impl Into<String>) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.post_ephemeral_async(arguments, user))
     }
 
-    /// Sends a message to a channel with text only asynchronously.
-    fn post_message_text_async(&self, channel: String, text: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
-        let arguments = ChatPostMessageArguments {
-            channel,
-            text: Option::from(text),
-            ..Default::default()
-        };
-        self.post_message_async(arguments)
+    /// Sends an ephemeral message, visible only to `user`, to a channel asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.postEphemeral>
+    fn post_ephemeral_async(&self, arguments: ChatPostMessageArguments, user: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Lists the pending scheduled messages, optionally only those of `channel`, following pagination.
+    ///
+    /// <https://api.slack.com/methods/chat.scheduledMessages.list>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn list_scheduled_messages(&self, channel: Option<String>) -> Result<Vec<ScheduledMessage>, SlackApiError> {
+        block_on_current_thread(self.list_scheduled_messages_async(channel))
     }
-}
 
+    /// Lists the pending scheduled messages, optionally only those of `channel`, following pagination, asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.scheduledMessages.list>
+    fn list_scheduled_messages_async(&self, channel: Option<String>) -> Pin<Box<dyn Future<Output=Result<Vec<ScheduledMessage>, SlackApiError>> + Send + '_>>;
 
-#[cfg(test)]
-mod chat_tests {
-    use std::env;
+    /// Sends the same message to several channels concurrently, overriding `arguments.channel` for each.
+    ///
+    /// Returns one result per channel, in the order of `channels`. The outer error is only returned
+    /// when the blocking call itself cannot run.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_multi(&self, channels: Vec<String>, arguments: ChatPostMessageArguments) -> Result<Vec<Result<String, SlackApiError>>, SlackApiError> {
+        block_on_current_thread(async { Ok(self.post_message_multi_async(channels, arguments).await) })
+    }
 
-    use super::*;
+    /// Sends the same message to several channels concurrently asynchronously, overriding `arguments.channel` for each.
+    ///
+    /// Returns one result per channel, in the order of `channels`.
+    #[allow(clippy::type_complexity)]
+    fn post_message_multi_async(&self, channels: Vec<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Vec<Result<String, SlackApiError>>> + Send + '_>>;
 
-    #[test]
-    fn chat_post_message_and_delete() {
-        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
-        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
-        let text = "Hello, Slack from Rust!";
+    /// Deletes several messages from a channel concurrently, with at most five requests in flight.
+    ///
+    /// Returns one result per timestamp, in the order of `timestamps`. The outer error is only returned
+    /// when the blocking call itself cannot run.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete_many(&self, channel: impl Into<String>, timestamps: Vec<String>) -> Result<Vec<Result<(), SlackApiError>>, SlackApiError> where Self: Sized {
+        block_on_current_thread(async { Ok(self.delete_many_async(channel, timestamps).await) })
+    }
 
-        let client = SlackClient::new(token.to_string());
-        let arguments = ChatPostMessageArguments {
-            channel: channel_id.to_string(),
-            text: Option::from(text.to_string()),
-            ..Default::default()
-        };
+    /// Deletes several messages from a channel concurrently asynchronously.
+    ///
+    /// Returns one result per timestamp, in the order of `timestamps`.
+    #[allow(clippy::type_complexity)]
+    fn delete_many_async(&self, channel: impl Into<String>, timestamps: Vec<String>) -> Pin<Box<dyn Future<Output=Vec<Result<(), SlackApiError>>> + Send + '_>> where Self: Sized;
 
-        // Post a message to the channel
-        let post = client.post_message(arguments);
-        assert!(post.is_ok(), "Failed to post message");
+    /// Sends a `/me` message to a channel, displayed in italics, and returns its ts.
+    ///
+    /// <https://api.slack.com/methods/chat.meMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_me_message(&self, channel: impl Into<String>, text: impl Into<String>) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.post_me_message_async(channel, text))
+    }
 
-        // Delete the message from the channel
-        let message_id = post.unwrap();
-        let delete = client.delete(channel_id, message_id);
-        assert!(delete.is_ok(), "Failed to delete message");
+    /// Sends a `/me` message to a channel asynchronously and returns its ts.
+    ///
+    /// <https://api.slack.com/methods/chat.meMessage>
+    fn post_me_message_async(&self, channel: impl Into<String>, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Sends a message unless one was already sent with the same `key`, returning the ts of the first message.
+    ///
+    /// Keys are only remembered in memory for the lifetime of the client and its clones, so this does not
+    /// prevent duplicates across restarts. Concurrent calls with the same key may still both post.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_once(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.post_message_once_async(key, arguments))
     }
 
-    #[test]
-    fn chat_post_message_txt_and_delete() {
-        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
-        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
-        let text = "Hello, Slack from Rust!";
+    /// Sends a message unless one was already sent with the same `key` asynchronously.
+    fn post_message_once_async(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
 
-        let client = SlackClient::new(token.to_string());
+    /// Sends a message, abandoning the request with `SlackApiError::Cancelled` once `cancel` is cancelled.
+    ///
+    /// A message may still be posted if Slack received the request before it was cancelled.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_cancellable(&self, arguments: ChatPostMessageArguments, cancel: CancellationToken) -> Result<String, SlackApiError> {
+        block_on_current_thread(self.post_message_cancellable_async(arguments, cancel))
+    }
 
-        // Post a message to the channel
-        let post = client.post_message_text(channel_id.to_string(), text.to_string());
-        assert!(post.is_ok(), "Failed to post message");
+    /// Sends a message until `cancel` is cancelled asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    fn post_message_cancellable_async(&self, arguments: ChatPostMessageArguments, cancel: CancellationToken) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>>;
 
-        // Delete the message from the channel
-        let message_id = post.unwrap();
-        let delete = client.delete(channel_id, message_id);
+    /// Sends a message the first time `key` is used, and updates that message with the text, blocks and
+    /// attachments of `arguments` on later calls, returning its ts. A message deleted in the meantime is posted again.
+    ///
+    /// Keys are only remembered in memory for the lifetime of the client and its clones.
+    ///
+    /// <https://api.slack.com/methods/chat.update>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn upsert_message(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.upsert_message_async(key, arguments))
+    }
+
+    /// Sends or updates the message of `key` asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.update>
+    fn upsert_message_async(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Sends a message starting a thread, returning a handle to reply to it.
+    ///
+    /// Returns `SlackApiError::InvalidArgument` if `thread_ts` is set, as the message would be a reply.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_thread(&self, arguments: ChatPostMessageArguments) -> Result<ThreadHandle<'_, Self>, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.post_thread_async(arguments))
+    }
+
+    /// Sends a message starting a thread asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    fn post_thread_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<ThreadHandle<'_, Self>, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Schedules a message at `local_time` in the time zone `tz`, e.g. 09:00 in `America/New_York`, accounting for
+    /// daylight saving time.
+    ///
+    /// A local time that occurs twice when clocks go back uses the first occurrence. A local time skipped when
+    /// clocks go forward returns `SlackApiError::InvalidArgument`.
+    ///
+    /// <https://api.slack.com/methods/chat.scheduleMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32"), feature = "chrono-tz"))]
+    fn schedule_message_at(&self, arguments: ChatPostMessageArguments, local_time: NaiveDateTime, tz: Tz) -> Result<String, SlackApiError>;
+
+    /// Schedules a message at a local time in a time zone asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.scheduleMessage>
+    #[cfg(feature = "chrono-tz")]
+    fn schedule_message_at_async(&self, arguments: ChatPostMessageArguments, local_time: NaiveDateTime, tz: Tz) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>>;
+
+    /// Deletes the message of a permalink like `https://example.slack.com/archives/C0000000000/p1700000000000100`.
+    ///
+    /// Returns `SlackApiError::InvalidArgument` if the URL is not a message permalink.
+    ///
+    /// <https://api.slack.com/methods/chat.delete>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete_by_permalink(&self, permalink: impl Into<String>) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.delete_by_permalink_async(permalink))
+    }
+
+    /// Deletes the message of a permalink asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.delete>
+    fn delete_by_permalink_async(&self, permalink: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Attaches previews to the links of a message, for apps subscribed to the `link_shared` event.
+    ///
+    /// `unfurls` is a JSON object mapping each URL to its preview, e.g. `{ "https://example.com/1": { "blocks": [...] } }`.
+    /// Returns `SlackApiError::CannotUnfurlUrl` if Slack refuses to unfurl one of the URLs.
+    ///
+    /// <https://api.slack.com/methods/chat.unfurl>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn unfurl(&self, channel: impl Into<String>, ts: impl Into<String>, unfurls: Value) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.unfurl_async(channel, ts, unfurls))
+    }
+
+    /// Attaches previews to the links of a message asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.unfurl>
+    fn unfurl_async(&self, channel: impl Into<String>, ts: impl Into<String>, unfurls: Value) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Posts `content` as a code block under a bold `title`, e.g. a small config dump, instead of uploading a file.
+    ///
+    /// The language is shown next to the title, since Slack does not highlight code blocks. The content is escaped
+    /// with `escape_mrkdwn`, since Slack parses mentions such as `<!here>` even in code blocks, and runs of three or
+    /// more backticks are broken up with zero-width spaces (U+200B) so they do not end the code block, which means
+    /// text copied from the message differs from `content` at those runs. A title or language
+    /// longer than 200 characters is cut off, and content that would exceed the 40,000 character limit is truncated,
+    /// with a notice of the number of characters left out after the code block.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_snippet(&self, channel: impl Into<String>, title: impl Into<String>, content: impl Into<String>, language: Option<String>) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.post_snippet_async(channel, title, content, language))
+    }
+
+    /// Posts `content` as a code block under a bold `title` asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    fn post_snippet_async(&self, channel: impl Into<String>, title: impl Into<String>, content: impl Into<String>, language: Option<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Sends a message to a channel and returns its ts as a `Ts`, rather than a `String` like `post_message`.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_ts(&self, arguments: ChatPostMessageArguments) -> Result<Ts, SlackApiError> {
+        block_on_current_thread(self.post_message_ts_async(arguments))
+    }
+
+    /// Sends a message to a channel and returns its ts as a `Ts` asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    fn post_message_ts_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<Ts, SlackApiError>> + Send + '_>>;
+
+    /// Sends a message to a channel with text only and returns its ts as a `Ts`.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_text_ts(&self, channel: impl Into<String>, text: impl Into<String>) -> Result<Ts, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.post_message_text_ts_async(channel, text))
+    }
+
+    /// Sends a message to a channel with text only and returns its ts as a `Ts` asynchronously.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    fn post_message_text_ts_async(&self, channel: impl Into<String>, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Ts, SlackApiError>> + Send + '_>> where Self: Sized;
+}
+
+/// Implement the Chat trait for SlackClient.
+impl Chat for SlackClient {
+    /// Deletes a message from a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete(&self, channel: impl Into<String>, ts: impl Into<String>) -> Result<(), SlackApiError> {
+        self.block_on(self.delete_async(channel, ts))
+    }
+
+    /// Deletes a message from a channel asynchronously.
+    fn delete_async(&self, channel: impl Into<String>, ts: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let ts: String = ts.into();
+
+        Box::pin(in_channel(&channel.clone(), async move {
+            self.post_form("chat.delete", &[("channel", &channel), ("ts", &ts)]).await?;
+            Ok(())
+        }))
+    }
+
+    /// Posts a message to a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message(&self, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError> {
+        self.block_on(self.post_message_async(arguments))
+    }
+
+    /// Posts a message to a channel asynchronously.
+    fn post_message_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let response = self.post_message_full_async(arguments);
+
+        Box::pin(async move {
+            // Extract the message ID from the response
+            Ok(response.await?.ts)
+        })
+    }
+
+    /// Posts a message to a channel and returns the full response.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_full(&self, arguments: ChatPostMessageArguments) -> Result<ChatPostMessageResponse, SlackApiError> {
+        self.block_on(self.post_message_full_async(arguments))
+    }
+
+    /// Posts a message to a channel and returns the full response asynchronously.
+    fn post_message_full_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<ChatPostMessageResponse, SlackApiError>> + Send + '_>> {
+
+        // Check the arguments before sending them to Slack
+        if let Err(e) = validate_post_message(&arguments) {
+            return Box::pin(async { Err(e) });
+        }
+        let arguments = normalize_icon_emoji(arguments);
+
+        // Send the request to the Slack API
+        Box::pin(in_channel(&arguments.channel.clone(), async move {
+            let (body, headers) = self.post_json_with_headers("chat.postMessage", &arguments).await?;
+
+            // Decode the response into the response struct
+            let response: ChatPostMessageResponse = decode_response(body)?;
+
+            // Log warnings such as deprecated_argument, to find the messages to migrate to blocks
+            #[cfg(feature = "tracing")]
+            if let Some(warning) = &response.warning {
+                tracing::warn!(warning = %warning, ts = %response.ts, "chat.postMessage returned a warning");
+            }
+            Ok(ChatPostMessageResponse { headers, ..response })
+        }))
+    }
+
+    /// Sends a message to a channel with text only.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_text(&self, channel: impl Into<String>, text: impl Into<String>) -> Result<String, SlackApiError> {
+        self.block_on(self.post_message_text_async(channel, text))
+    }
+
+    /// Sends a message to a channel with text only asynchronously.
+    fn post_message_text_async(&self, channel: impl Into<String>, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let text: String = text.into();
+
+        let arguments = ChatPostMessageArguments {
+            channel,
+            text: Option::from(text),
+            ..Default::default()
+        };
+        self.post_message_async(arguments)
+    }
+
+    /// Sends a message with text only to the client's default channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_text_default(&self, text: impl Into<String>) -> Result<String, SlackApiError> {
+        self.block_on(self.post_message_text_default_async(text))
+    }
+
+    /// Sends a message with text only to the client's default channel asynchronously.
+    fn post_message_text_default_async(&self, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let text: String = text.into();
+
+        let Some(channel) = self.default_channel.clone() else {
+            return Box::pin(async { Err(SlackApiError::InvalidArgument("default_channel is not set".into())) });
+        };
+        self.post_message_text_async(channel, text)
+    }
+
+    /// Replies to a thread with text only.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn reply_in_thread(&self, channel: impl Into<String>, thread_ts: impl Into<String>, text: impl Into<String>, broadcast: bool) -> Result<String, SlackApiError> {
+        self.block_on(self.reply_in_thread_async(channel, thread_ts, text, broadcast))
+    }
+
+    /// Replies to a thread with text only asynchronously.
+    fn reply_in_thread_async(&self, channel: impl Into<String>, thread_ts: impl Into<String>, text: impl Into<String>, broadcast: bool) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let thread_ts: String = thread_ts.into();
+        let text: String = text.into();
+
+        let arguments = ChatPostMessageArguments {
+            channel,
+            text: Option::from(text),
+            thread_ts: Option::from(thread_ts),
+            reply_broadcast: broadcast.then_some(true),
+            ..Default::default()
+        };
+        self.post_message_async(arguments)
+    }
+
+    /// Updates a message in a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn update(&self, arguments: ChatUpdateArguments) -> Result<String, SlackApiError> {
+        self.block_on(self.update_async(arguments))
+    }
+
+    /// Updates a message in a channel asynchronously.
+    fn update_async(&self, arguments: ChatUpdateArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+
+        // Check if the text, attachments, or blocks fields are provided
+        if let Err(e) = validate_content(&arguments.text, &arguments.attachments, &arguments.blocks) {
+            return Box::pin(async { Err(e) });
+        }
+
+        // Send the request to the Slack API
+        Box::pin(in_channel(&arguments.channel.clone(), async move {
+            let body = self.post_json("chat.update", &arguments).await?;
+
+            // Extract the message ID from the JSON
+            let message_id = body["ts"].as_str().ok_or(SlackApiError::InvalidArgument("No message ID in response".into()))?.to_string();
+
+            Ok(message_id)
+        }))
+    }
+
+    /// Schedules a message to be sent to a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn schedule_message(&self, arguments: ChatPostMessageArguments, post_at: i64) -> Result<String, SlackApiError> {
+        self.block_on(self.schedule_message_async(arguments, post_at))
+    }
+
+    /// Schedules a message to be sent to a channel asynchronously.
+    fn schedule_message_async(&self, arguments: ChatPostMessageArguments, post_at: i64) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+
+        // Check the arguments before sending them to Slack
+        if let Err(e) = validate_post_message(&arguments) {
+            return Box::pin(async { Err(e) });
+        }
+        let arguments = normalize_icon_emoji(arguments);
+
+        // Check if the post_at timestamp is in the future, within the limit of Slack, where there is a clock
+        if let Some(now) = unix_now() {
+            if post_at < now - SCHEDULE_SKEW_SECS {
+                return Box::pin(async { Err(SlackApiError::InvalidArgument("post_at must be in the future".into())) });
+            }
+            if post_at > now + MAX_SCHEDULE_AHEAD_SECS {
+                return Box::pin(async { Err(SlackApiError::InvalidArgument("post_at exceeds 120 day limit".into())) });
+            }
+        }
+
+        // Send the request to the Slack API
+        Box::pin(async move {
+            let request = ChatScheduleMessageRequest { arguments, post_at };
+            let body = self.post_json("chat.scheduleMessage", &request).await?;
+
+            // Extract the scheduled message ID from the JSON
+            let scheduled_message_id = body["scheduled_message_id"].as_str().ok_or(SlackApiError::InvalidArgument("No scheduled message ID in response".into()))?.to_string();
+
+            Ok(scheduled_message_id)
+        })
+    }
+
+    /// Deletes a pending scheduled message from the queue.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete_scheduled_message(&self, channel: impl Into<String>, scheduled_message_id: impl Into<String>) -> Result<(), SlackApiError> {
+        self.block_on(self.delete_scheduled_message_async(channel, scheduled_message_id))
+    }
+
+    /// Deletes a pending scheduled message from the queue asynchronously.
+    fn delete_scheduled_message_async(&self, channel: impl Into<String>, scheduled_message_id: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let scheduled_message_id: String = scheduled_message_id.into();
+
+        Box::pin(async move {
+            self.post_form("chat.deleteScheduledMessage", &[("channel", &channel), ("scheduled_message_id", &scheduled_message_id)]).await?;
+            Ok(())
+        })
+    }
+
+    /// Retrieves a permalink URL for a message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn get_permalink(&self, channel: impl Into<String>, message_ts: impl Into<String>) -> Result<String, SlackApiError> {
+        self.block_on(self.get_permalink_async(channel, message_ts))
+    }
+
+    /// Retrieves a permalink URL for a message asynchronously.
+    fn get_permalink_async(&self, channel: impl Into<String>, message_ts: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let message_ts: String = message_ts.into();
+
+        Box::pin(async move {
+            let body = self.get_query("chat.getPermalink", &[("channel", &channel), ("message_ts", &message_ts)]).await?;
+
+            // Extract the permalink from the JSON
+            let permalink = body["permalink"].as_str().ok_or(SlackApiError::InvalidArgument("No permalink in response".into()))?.to_string();
+
+            Ok(permalink)
+        })
+    }
+
+    /// Sends an ephemeral message to a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_ephemeral(&self, arguments: ChatPostMessageArguments, user: impl Into<String>) -> Result<String, SlackApiError> {
+        self.block_on(self.post_ephemeral_async(arguments, user))
+    }
+
+    /// Sends an ephemeral message to a channel asynchronously.
+    fn post_ephemeral_async(&self, arguments: ChatPostMessageArguments, user: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let user: String = user.into();
+
+        // Check the arguments before sending them to Slack
+        if let Err(e) = validate_post_message(&arguments) {
+            return Box::pin(async { Err(e) });
+        }
+        let arguments = normalize_icon_emoji(arguments);
+
+        // Check if the user field is provided
+        if user.is_empty() {
+            return Box::pin(async { Err(SlackApiError::InvalidArgument("user is required".into())) });
+        }
+
+        // Send the request to the Slack API
+        Box::pin(async move {
+            let request = ChatPostEphemeralRequest { arguments, user };
+            let body = self.post_json("chat.postEphemeral", &request).await?;
+
+            // Extract the message ID from the JSON
+            let message_id = body["message_ts"].as_str().ok_or(SlackApiError::InvalidArgument("No message ID in response".into()))?.to_string();
+
+            Ok(message_id)
+        })
+    }
+
+    /// Lists the pending scheduled messages.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn list_scheduled_messages(&self, channel: Option<String>) -> Result<Vec<ScheduledMessage>, SlackApiError> {
+        self.block_on(self.list_scheduled_messages_async(channel))
+    }
+
+    /// Lists the pending scheduled messages asynchronously.
+    fn list_scheduled_messages_async(&self, channel: Option<String>) -> Pin<Box<dyn Future<Output=Result<Vec<ScheduledMessage>, SlackApiError>> + Send + '_>> {
+        Box::pin(async move {
+            let mut scheduled_messages = Vec::new();
+            let mut cursor: Option<String> = None;
+
+            loop {
+                let mut form = Vec::new();
+                if let Some(channel) = &channel {
+                    form.push(("channel", channel.clone()));
+                }
+                if let Some(cursor) = &cursor {
+                    form.push(("cursor", cursor.clone()));
+                }
+
+                let body = self.post_form("chat.scheduledMessages.list", &form).await?;
+                let page: Vec<ScheduledMessage> = decode_response(body["scheduled_messages"].clone())?;
+                scheduled_messages.extend(page);
+
+                // Follow the cursor until there are no more pages
+                cursor = next_cursor(&body);
+                if cursor.is_none() {
+                    break;
+                }
+            }
+
+            Ok(scheduled_messages)
+        })
+    }
+
+    /// Sends the same message to several channels concurrently.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_multi(&self, channels: Vec<String>, arguments: ChatPostMessageArguments) -> Result<Vec<Result<String, SlackApiError>>, SlackApiError> {
+        self.block_on(async { Ok(self.post_message_multi_async(channels, arguments).await) })
+    }
+
+    /// Sends the same message to several channels concurrently asynchronously.
+    #[allow(clippy::type_complexity)]
+    fn post_message_multi_async(&self, channels: Vec<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Vec<Result<String, SlackApiError>>> + Send + '_>> {
+        let posts = channels.into_iter().map(|channel| {
+            let arguments = ChatPostMessageArguments { channel, ..arguments.clone() };
+            self.post_message_async(arguments)
+        });
+
+        Box::pin(join_all(posts))
+    }
+
+    /// Deletes several messages from a channel concurrently.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete_many(&self, channel: impl Into<String>, timestamps: Vec<String>) -> Result<Vec<Result<(), SlackApiError>>, SlackApiError> {
+        self.block_on(async { Ok(self.delete_many_async(channel, timestamps).await) })
+    }
+
+    /// Deletes several messages from a channel concurrently asynchronously.
+    #[allow(clippy::type_complexity)]
+    fn delete_many_async(&self, channel: impl Into<String>, timestamps: Vec<String>) -> Pin<Box<dyn Future<Output=Vec<Result<(), SlackApiError>>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        // Keep the number of requests in flight small to stay clear of rate limits, preserving the order of the results
+        let deletes = stream::iter(timestamps)
+            .map(move |ts| self.delete_async(channel.clone(), ts))
+            .buffered(DELETE_CONCURRENCY);
+
+        Box::pin(deletes.collect())
+    }
+
+    /// Sends a `/me` message to a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_me_message(&self, channel: impl Into<String>, text: impl Into<String>) -> Result<String, SlackApiError> {
+        self.block_on(self.post_me_message_async(channel, text))
+    }
+
+    /// Sends a `/me` message to a channel asynchronously.
+    fn post_me_message_async(&self, channel: impl Into<String>, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let text: String = text.into();
+
+        if text.is_empty() {
+            return Box::pin(async { Err(SlackApiError::InvalidArgument("text is required".into())) });
+        }
+
+        Box::pin(async move {
+            let body = self.post_form("chat.meMessage", &[("channel", &channel), ("text", &text)]).await?;
+
+            // Extract the message ID from the JSON
+            let message_id = body["ts"].as_str().ok_or(SlackApiError::InvalidArgument("No message ID in response".into()))?.to_string();
+
+            Ok(message_id)
+        })
+    }
+
+    /// Sends a message unless one was already sent with the same key.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_once(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError> {
+        self.block_on(self.post_message_once_async(key, arguments))
+    }
+
+    /// Sends a message unless one was already sent with the same key asynchronously.
+    fn post_message_once_async(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let key: String = key.into();
+
+        Box::pin(async move {
+            // Return the message of an earlier call with the same key
+            if let Some(ts) = self.posted_once.lock().unwrap().get(&key) {
+                return Ok(ts.clone());
+            }
+
+            let ts = self.post_message_async(arguments).await?;
+            self.posted_once.lock().unwrap().insert(key, ts.clone());
+
+            Ok(ts)
+        })
+    }
+
+    /// Sends a message until the token is cancelled.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_cancellable(&self, arguments: ChatPostMessageArguments, cancel: CancellationToken) -> Result<String, SlackApiError> {
+        self.block_on(self.post_message_cancellable_async(arguments, cancel))
+    }
+
+    /// Sends a message until the token is cancelled asynchronously.
+    fn post_message_cancellable_async(&self, arguments: ChatPostMessageArguments, cancel: CancellationToken) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        Box::pin(async move {
+            // Poll the token first, so an already cancelled token never sends the request
+            let cancelled = Box::pin(cancel.cancelled());
+            match future::select(cancelled, self.post_message_async(arguments)).await {
+                Either::Left(_) => Err(SlackApiError::Cancelled),
+                Either::Right((result, _)) => result,
+            }
+        })
+    }
+
+    /// Sends or updates the message of a key.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn upsert_message(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError> {
+        self.block_on(self.upsert_message_async(key, arguments))
+    }
+
+    /// Sends or updates the message of a key asynchronously.
+    fn upsert_message_async(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let key: String = key.into();
+
+        Box::pin(async move {
+            // Update the message posted by an earlier call, unless it has been deleted
+            let existing = self.upserted.lock().unwrap().get(&key).cloned();
+            if let Some((channel, ts)) = existing {
+                let update = ChatUpdateArguments {
+                    channel,
+                    ts,
+                    text: arguments.text.clone(),
+                    blocks: arguments.blocks.clone(),
+                    attachments: arguments.attachments.clone(),
+                };
+                match self.update_async(update).await {
+                    Err(SlackApiError::MessageNotFound) => {}
+                    result => return result,
+                }
+            }
+
+            // Post the message and remember where it went
+            let response = self.post_message_full_async(arguments).await?;
+            self.upserted.lock().unwrap().insert(key, (response.channel, response.ts.clone()));
+
+            Ok(response.ts)
+        })
+    }
+
+    /// Sends a message starting a thread.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_thread(&self, arguments: ChatPostMessageArguments) -> Result<ThreadHandle<'_, Self>, SlackApiError> {
+        self.block_on(self.post_thread_async(arguments))
+    }
+
+    /// Sends a message starting a thread asynchronously.
+    fn post_thread_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<ThreadHandle<'_, Self>, SlackApiError>> + Send + '_>> {
+        start_thread(self, arguments)
+    }
+
+    /// Schedules a message at a local time in a time zone.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32"), feature = "chrono-tz"))]
+    fn schedule_message_at(&self, arguments: ChatPostMessageArguments, local_time: NaiveDateTime, tz: Tz) -> Result<String, SlackApiError> {
+        self.block_on(self.schedule_message_at_async(arguments, local_time, tz))
+    }
+
+    /// Schedules a message at a local time in a time zone asynchronously.
+    #[cfg(feature = "chrono-tz")]
+    fn schedule_message_at_async(&self, arguments: ChatPostMessageArguments, local_time: NaiveDateTime, tz: Tz) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        match local_post_at(local_time, tz) {
+            Ok(post_at) => self.schedule_message_async(arguments, post_at),
+            Err(e) => Box::pin(async { Err(e) }),
+        }
+    }
+
+    /// Deletes the message of a permalink.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete_by_permalink(&self, permalink: impl Into<String>) -> Result<(), SlackApiError> {
+        self.block_on(self.delete_by_permalink_async(permalink))
+    }
+
+    /// Deletes the message of a permalink asynchronously.
+    fn delete_by_permalink_async(&self, permalink: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let permalink: String = permalink.into();
+
+        match parse_permalink(&permalink) {
+            Ok((channel, ts)) => self.delete_async(channel, ts),
+            Err(e) => Box::pin(async { Err(e) }),
+        }
+    }
+
+    /// Attaches previews to the links of a message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn unfurl(&self, channel: impl Into<String>, ts: impl Into<String>, unfurls: Value) -> Result<(), SlackApiError> {
+        self.block_on(self.unfurl_async(channel, ts, unfurls))
+    }
+
+    /// Attaches previews to the links of a message asynchronously.
+    fn unfurl_async(&self, channel: impl Into<String>, ts: impl Into<String>, unfurls: Value) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let ts: String = ts.into();
+
+        if let Err(e) = validate_unfurls(&unfurls) {
+            return Box::pin(async { Err(e) });
+        }
+
+        Box::pin(async move {
+            self.post_json("chat.unfurl", &json!({ "channel": channel, "ts": ts, "unfurls": unfurls })).await?;
+            Ok(())
+        })
+    }
+
+    /// Posts a code snippet.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_snippet(&self, channel: impl Into<String>, title: impl Into<String>, content: impl Into<String>, language: Option<String>) -> Result<String, SlackApiError> {
+        self.block_on(self.post_snippet_async(channel, title, content, language))
+    }
+
+    /// Posts a code snippet asynchronously.
+    fn post_snippet_async(&self, channel: impl Into<String>, title: impl Into<String>, content: impl Into<String>, language: Option<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let text = snippet_text(&title.into(), &content.into(), language.as_deref());
+        self.post_message_text_async(channel, text)
+    }
+
+    /// Sends a message to a channel and returns its ts as a Ts.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_ts(&self, arguments: ChatPostMessageArguments) -> Result<Ts, SlackApiError> {
+        self.block_on(self.post_message_ts_async(arguments))
+    }
+
+    /// Sends a message to a channel and returns its ts as a Ts asynchronously.
+    fn post_message_ts_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<Ts, SlackApiError>> + Send + '_>> {
+        let post = self.post_message_async(arguments);
+        Box::pin(async move { post.await?.parse() })
+    }
+
+    /// Sends a message with text only and returns its ts as a Ts.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_text_ts(&self, channel: impl Into<String>, text: impl Into<String>) -> Result<Ts, SlackApiError> {
+        self.block_on(self.post_message_text_ts_async(channel, text))
+    }
+
+    /// Sends a message with text only and returns its ts as a Ts asynchronously.
+    fn post_message_text_ts_async(&self, channel: impl Into<String>, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Ts, SlackApiError>> + Send + '_>> {
+        let post = self.post_message_text_async(channel, text);
+        Box::pin(async move { post.await?.parse() })
+    }
+}
+
+/// Format `content` as a code block under a bold title, within the text length limit.
+pub(crate) fn snippet_text(title: &str, content: &str, language: Option<&str>) -> String {
+    let header = match language {
+        Some(language) => format!("*{}* ({})", escape_mrkdwn(&truncate_title(title)), escape_mrkdwn(&truncate_title(language))),
+        None => format!("*{}*", escape_mrkdwn(&truncate_title(title))),
+    };
+
+    // Slack parses mentions and links in code blocks too, and shows the entities as the characters they stand for
+    let mut content = escape_mrkdwn(content.trim_end_matches('\n'));
+
+    // Zero-width spaces keep the backtick runs in the content from closing the block, until no run of three is left
+    while content.contains("```") {
+        content = content.replace("```", "`\u{200b}``");
+    }
+
+    // Fit the header, the fences, and the notice after them in the limit, and keep as much content as possible
+    let length = content.chars().count();
+    let frame = header.chars().count() + "\n```\n\n```".len();
+    if frame + length <= MAX_TEXT_LEN {
+        return format!("{}\n```\n{}\n```", header, content);
+    }
+    // The notice for all the content is at least as long as the final one, which counts fewer characters
+    let notice_len = format!("\n… truncated {} characters", length).chars().count();
+    let kept = MAX_TEXT_LEN.saturating_sub(frame + notice_len);
+    let mut kept: String = content.chars().take(kept).collect();
+    // Every `&` starts an entity, so one without its `;` was cut off
+    if let Some(amp) = kept.rfind('&').filter(|&amp| !kept[amp..].contains(';')) {
+        kept.truncate(amp);
+    }
+    let notice = format!("\n… truncated {} characters", length - kept.chars().count());
+
+    format!("{}\n```\n{}\n```{}", header, kept, notice)
+}
+
+/// Cut `title` off at MAX_SNIPPET_TITLE_LEN characters, marking the cut with an ellipsis.
+fn truncate_title(title: &str) -> String {
+    if title.chars().count() <= MAX_SNIPPET_TITLE_LEN {
+        return title.to_string();
+    }
+    let kept: String = title.chars().take(MAX_SNIPPET_TITLE_LEN - 1).collect();
+    format!("{}…", kept)
+}
+
+/// Check that `unfurls` is a non-empty JSON object of previews keyed by URL.
+pub(crate) fn validate_unfurls(unfurls: &Value) -> Result<(), SlackApiError> {
+    match unfurls.as_object() {
+        Some(unfurls) if !unfurls.is_empty() => Ok(()),
+        _ => Err(SlackApiError::InvalidArgument("unfurls must be a JSON object keyed by URL".into())),
+    }
+}
+
+/// Post the root message of a thread with `chat`, returning a handle to the thread.
+#[allow(clippy::type_complexity)]
+pub(crate) fn start_thread<C: Chat>(chat: &C, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<ThreadHandle<'_, C>, SlackApiError>> + Send + '_>> {
+    // A message with thread_ts is a reply, not the start of a thread
+    if arguments.thread_ts.is_some() {
+        return Box::pin(async { Err(SlackApiError::InvalidArgument("thread_ts must not be set to start a thread".into())) });
+    }
+
+    Box::pin(async move {
+        let root = chat.post_message_full_async(arguments).await?;
+        Ok(ThreadHandle { chat, root })
+    })
+}
+
+/// Parse the channel ID and message ts out of a permalink like `https://example.slack.com/archives/C0000000000/p1700000000000100`.
+///
+/// The `p` segment is the ts without its dot, the last six digits being the microseconds.
+pub(crate) fn parse_permalink(permalink: &str) -> Result<(String, String), SlackApiError> {
+    let invalid = || SlackApiError::InvalidArgument(format!("not a message permalink: {}", permalink));
+
+    // Drop the query, e.g. the thread_ts of a reply, and look at the last two path segments
+    let path = permalink.split(['?', '#']).next().unwrap_or_default().trim_end_matches('/');
+    let mut segments = path.rsplit('/');
+    let (Some(message), Some(channel), Some("archives")) = (segments.next(), segments.next(), segments.next()) else {
+        return Err(invalid());
+    };
+
+    let digits = message.strip_prefix('p').filter(|digits| digits.len() > 6 && digits.bytes().all(|b| b.is_ascii_digit())).ok_or_else(invalid)?;
+    if channel.is_empty() {
+        return Err(invalid());
+    }
+    let (seconds, micros) = digits.split_at(digits.len() - 6);
+
+    Ok((channel.to_string(), format!("{}.{}", seconds, micros)))
+}
+
+/// Convert a local time in a time zone to the Unix timestamp to schedule a message at.
+#[cfg(feature = "chrono-tz")]
+pub(crate) fn local_post_at(local_time: NaiveDateTime, tz: Tz) -> Result<i64, SlackApiError> {
+    match tz.from_local_datetime(&local_time) {
+        LocalResult::Single(time) => Ok(time.timestamp()),
+        // The time occurs twice when clocks go back, use the first one
+        LocalResult::Ambiguous(earliest, _) => Ok(earliest.timestamp()),
+        LocalResult::None => Err(SlackApiError::InvalidArgument(format!("{} does not exist in {}", local_time, tz))),
+    }
+}
+
+/// Wrap a bare emoji name in `icon_emoji`, e.g. `rocket`, in colons, as Slack ignores the icon otherwise.
+fn normalize_icon_emoji(mut arguments: ChatPostMessageArguments) -> ChatPostMessageArguments {
+    if let Some(icon_emoji) = &arguments.icon_emoji {
+        let name = icon_emoji.trim().trim_matches(':');
+        if !name.is_empty() {
+            arguments.icon_emoji = Some(format!(":{}:", name));
+        }
+    }
+    arguments
+}
+
+/// Check the arguments of a message before it is posted.
+pub(crate) fn validate_post_message(arguments: &ChatPostMessageArguments) -> Result<(), SlackApiError> {
+    validate_content(&arguments.text, &arguments.attachments, &arguments.blocks)?;
+
+    // Check if the thread_ts field looks like a message timestamp
+    if let Some(thread_ts) = &arguments.thread_ts {
+        if !is_valid_ts(thread_ts) {
+            return Err(SlackApiError::InvalidArgument(format!("thread_ts must be a message timestamp like 1234567890.123456, got {:?}", thread_ts)));
+        }
+    }
+    Ok(())
+}
+
+/// Check that a message timestamp has the `seconds.micros` form, e.g. `1234567890.123456`.
+fn is_valid_ts(ts: &str) -> bool {
+    let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    match ts.split_once('.') {
+        Some((seconds, micros)) => is_digits(seconds) && is_digits(micros),
+        None => false,
+    }
+}
+
+/// Check that at least one of the text, attachments, or blocks fields is provided.
+pub(crate) fn validate_content(text: &Option<String>, attachments: &Option<Vec<ChatPostMessageAttachment>>, blocks: &Option<Vec<Value>>) -> Result<(), SlackApiError> {
+    if text.is_none() && attachments.is_none() && blocks.is_none() {
+        return Err(SlackApiError::InvalidArgument("text, attachments, or blocks is required".into()));
+    }
+
+    // Check the limits locally, Slack's own error for these is not very helpful
+    if text.as_ref().is_some_and(|text| text.chars().count() > MAX_TEXT_LEN) {
+        return Err(SlackApiError::InvalidArgument(format!("text exceeds {} characters", MAX_TEXT_LEN)));
+    }
+    if blocks.as_ref().is_some_and(|blocks| blocks.len() > MAX_BLOCKS) {
+        return Err(SlackApiError::InvalidArgument(format!("blocks exceeds {}", MAX_BLOCKS)));
+    }
+    if attachments.as_ref().is_some_and(|attachments| attachments.len() > MAX_ATTACHMENTS) {
+        return Err(SlackApiError::InvalidArgument(format!("attachments exceeds {}", MAX_ATTACHMENTS)));
+    }
+    Ok(())
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod chat_tests {
+    use std::env;
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use serde_json::json;
+    use tokio::runtime::Runtime;
+    use wiremock::matchers::{body_partial_json, body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::slack_client::DRY_RUN_TS;
+    use crate::Text;
+
+    use super::*;
+
+    #[test]
+    fn chat_post_message_and_delete() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+        let text = "Hello, Slack from Rust!";
+
+        let client = SlackClient::new(token.to_string());
+        let arguments = ChatPostMessageArguments {
+            channel: channel_id.to_string(),
+            text: Option::from(text.to_string()),
+            ..Default::default()
+        };
+
+        // Post a message to the channel
+        let post = client.post_message(arguments);
+        assert!(post.is_ok(), "Failed to post message");
+
+        // Delete the message from the channel
+        let message_id = post.unwrap();
+        let delete = client.delete(channel_id, message_id);
+        assert!(delete.is_ok(), "Failed to delete message");
+    }
+
+    #[test]
+    fn chat_post_message_txt_and_delete() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+        let text = "Hello, Slack from Rust!";
+
+        let client = SlackClient::new(token.to_string());
+
+        // Post a message to the channel
+        let post = client.post_message_text(channel_id.to_string(), text.to_string());
+        assert!(post.is_ok(), "Failed to post message");
+
+        // Delete the message from the channel
+        let message_id = post.unwrap();
+        let delete = client.delete(channel_id, message_id);
+        assert!(delete.is_ok(), "Failed to delete message");
+    }
+
+    #[test]
+    fn chat_post_message_text_default() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .and(body_string_contains("C0000000000"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": "C0000000000",
+                    "ts": "1700000000.000100",
+                    "message": { "text": "Hello, Slack from Rust!" },
+                })))
+                .mount(&server)
+        );
+
+        // The message goes to the default channel
+        let client = SlackClient {
+            default_channel: Some("C0000000000".to_string()),
+            ..SlackClient::with_base_url("xoxb-test".to_string(), server.uri())
+        };
+        let post = client.post_message_text_default("Hello, Slack from Rust!".to_string());
+        assert_eq!(post.unwrap(), "1700000000.000100");
+
+        // Without a default channel there is nowhere to post
+        let client = SlackClient::new("xoxb-test".to_string());
+        let post = client.post_message_text_default("Hello, Slack from Rust!".to_string());
+        assert_eq!(post.unwrap_err(), SlackApiError::InvalidArgument("default_channel is not set".into()));
+    }
+
+    #[test]
+    fn chat_delete_many_reports_each_message() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .and(body_string_contains("1700000000.000300"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "message_not_found" })))
+                .with_priority(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })))
+                .expect(7)
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let timestamps: Vec<String> = (1..=8).map(|n| format!("1700000000.{:06}", n * 100)).collect();
+        let deletes = client.delete_many("C0000000000".to_string(), timestamps).unwrap();
+
+        // Every message gets its own result, in order
+        assert_eq!(deletes.len(), 8);
+        assert_eq!(deletes[2], Err(SlackApiError::MessageNotFound));
+        assert!(deletes.iter().enumerate().all(|(i, delete)| i == 2 || delete.is_ok()));
+    }
+
+    #[test]
+    fn chat_post_message_once_skips_duplicates() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": "C0000000000",
+                    "ts": "1700000000.000100",
+                    "message": { "text": "Nightly report" },
+                })))
+                .expect(2)
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let arguments = ChatPostMessageArguments::builder("C0000000000")
+            .text("Nightly report")
+            .build();
+
+        // The second post with the same key returns the first ts without posting, also through a clone
+        assert_eq!(client.post_message_once("report-2023-11-14", arguments.clone()).unwrap(), "1700000000.000100");
+        assert_eq!(client.clone().post_message_once("report-2023-11-14", arguments.clone()).unwrap(), "1700000000.000100");
+
+        // Another key posts again
+        assert_eq!(client.post_message_once("report-2023-11-15", arguments).unwrap(), "1700000000.000100");
+    }
+
+    #[test]
+    fn chat_post_message_cancellable() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .respond_with(ResponseTemplate::new(200)
+                    .set_body_json(json!({ "ok": true, "channel": "C0000000000", "ts": "1700000000.000100" }))
+                    .set_delay(Duration::from_secs(5)))
+                .expect(1)
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let arguments = ChatPostMessageArguments::builder("C0000000000")
+            .text("Shutting down")
+            .build();
+
+        // An already cancelled token does not send the request
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        assert_eq!(client.post_message_cancellable(arguments.clone(), cancel), Err(SlackApiError::Cancelled));
+
+        // Cancelling the token abandons the request in flight
+        let cancel = CancellationToken::new();
+        let trigger = cancel.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            trigger.cancel();
+        });
+        assert_eq!(client.post_message_cancellable(arguments, cancel), Err(SlackApiError::Cancelled));
+    }
+
+    #[test]
+    fn chat_upsert_message() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": "C0000000000",
+                    "ts": "1700000000.000100",
+                    "message": { "text": "Deploy: running" },
+                })))
+                .expect(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.update"))
+                .and(body_string_contains("\"ts\":\"1700000000.000100\""))
+                .and(body_string_contains("Deploy: done"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": "C0000000000",
+                    "ts": "1700000000.000100",
+                    "text": "Deploy: done",
+                })))
+                .expect(1)
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+
+        // The first call posts the message, and the second updates it in place
+        let running = ChatPostMessageArguments::builder("#deploys").text("Deploy: running").build();
+        assert_eq!(client.upsert_message("deploy", running).unwrap(), "1700000000.000100");
+        let done = ChatPostMessageArguments::builder("#deploys").text("Deploy: done").build();
+        assert_eq!(client.upsert_message("deploy", done).unwrap(), "1700000000.000100");
+    }
+
+    #[test]
+    fn chat_post_me_message_and_delete() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Post a /me message to the channel
+        let post = client.post_me_message(channel_id.to_string(), "is testing rustslack".to_string());
+        assert!(post.is_ok(), "Failed to post me message");
+
+        // Delete the message from the channel
+        let delete = client.delete(channel_id, post.unwrap());
+        assert!(delete.is_ok(), "Failed to delete message");
+    }
+
+    #[test]
+    fn chat_post_me_message_requires_text() {
+        let client = SlackClient::new("xoxb-test".to_string());
+
+        // Posting an empty /me message is rejected locally
+        let post = client.post_me_message("C0000000000".to_string(), String::new());
+        assert_eq!(post.unwrap_err(), SlackApiError::InvalidArgument("text is required".into()));
+    }
+
+    #[test]
+    fn chat_reply_in_thread() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .and(body_string_contains(r#""thread_ts":"1700000000.000100""#))
+                .and(body_string_contains(r#""reply_broadcast":true"#))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": "C0000000000",
+                    "ts": "1700000000.000200",
+                    "message": { "text": "Hello, thread!", "thread_ts": "1700000000.000100" },
+                })))
+                .mount(&server)
+        );
+
+        // The reply is posted to the thread and broadcast to the channel
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let reply = client.reply_in_thread("C0000000000".to_string(), "1700000000.000100".to_string(), "Hello, thread!".to_string(), true);
+        assert_eq!(reply.unwrap(), "1700000000.000200");
+    }
+
+    #[test]
+    fn chat_post_message_full_decodes_message() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": "C0000000000",
+                    "ts": "1700000000.000100",
+                    "message": {
+                        "type": "message",
+                        "ts": "1700000000.000100",
+                        "text": "Build passed",
+                        "bot_id": "B0000000000",
+                        "app_id": "A0000000000",
+                        "username": "ci-bot",
+                        "blocks": [{ "type": "rich_text", "block_id": "abc" }],
+                    },
+                })))
+                .mount(&server)
+        );
+
+        // The echoed message is decoded, leaving out what Slack did not send
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let response = client.post_message_full(ChatPostMessageArguments::builder("C0000000000").text("Build passed").build()).unwrap();
+        assert_eq!(response.message, PostedMessage {
+            ts: Some("1700000000.000100".to_string()),
+            text: Some("Build passed".to_string()),
+            user: None,
+            bot_id: Some("B0000000000".to_string()),
+            app_id: Some("A0000000000".to_string()),
+            username: Some("ci-bot".to_string()),
+            blocks: Some(vec![json!({ "type": "rich_text", "block_id": "abc" })]),
+            thread_ts: None,
+        });
+    }
+
+    #[test]
+    fn chat_trait_object() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true, "channel": "C0000000000", "ts": "1700000000.000100" })))
+                .expect(1)
+                .mount(&server)
+        );
+
+        // The client can be shared as a trait object and used from a spawned task
+        let chat: Arc<dyn Chat> = Arc::new(SlackClient::with_base_url("xoxb-test".to_string(), server.uri()));
+        let arguments = ChatPostMessageArguments::builder("C0000000000").text("Hello, Slack!").build();
+        let task = runtime.spawn({
+            let chat = chat.clone();
+            async move { chat.post_message_async(arguments).await }
+        });
+        assert_eq!(runtime.block_on(task).unwrap().unwrap(), "1700000000.000100");
+    }
+
+    #[test]
+    fn chat_post_thread_and_reply() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .and(body_string_contains("Deploy started"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": "C0000000000",
+                    "ts": "1700000000.000100",
+                    "message": { "ts": "1700000000.000100", "text": "Deploy started" },
+                })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .and(body_string_contains("\"thread_ts\":\"1700000000.000100\""))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": "C0000000000",
+                    "ts": "1700000000.000200",
+                    "message": { "ts": "1700000000.000200", "text": "Step 1 done", "thread_ts": "1700000000.000100" },
+                })))
+                .expect(1)
+                .mount(&server)
+        );
+
+        // The root message starts the thread, and replies go to it
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let thread = client.post_thread(ChatPostMessageArguments::builder("#deploys").text("Deploy started").build()).unwrap();
+        assert!(thread.root.is_thread_root());
+        assert_eq!(thread.channel(), "C0000000000");
+        assert_eq!(thread.reply("Step 1 done").unwrap(), "1700000000.000200");
+
+        // A reply cannot start a thread
+        let reply = ChatPostMessageArguments::builder("C0000000000").text("Hello").thread_ts("1700000000.000100").build();
+        assert!(matches!(client.post_thread(reply), Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error");
+    }
+
+    #[test]
+    fn chat_post_message_ts() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true, "channel": "C0000000000", "ts": "1700000000.000100" })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .and(body_string_contains("ts=1700000000.000100"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })))
+                .expect(1)
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let ts = client.post_message_text_ts("C0000000000", "Hello, Slack from Rust!").unwrap();
+        assert_eq!(ts, Ts::new(1700000000, 100).unwrap());
+
+        // The typed ts converts into the String taken by delete
+        assert_eq!(client.delete("C0000000000", ts), Ok(()));
+    }
+
+    #[test]
+    fn chat_post_message_dry_run() {
+        let client = SlackClient::with_dry_run("xoxb-test".to_string());
+        let arguments = ChatPostMessageArguments::builder("C0000000000")
+            .text("Hello, Slack from Rust!")
+            .build();
+
+        // Nothing is sent and the response carries the message of the request
+        let response = client.post_message_full(arguments).unwrap();
+        assert_eq!(response.ts, DRY_RUN_TS);
+        assert_eq!(response.message.text.as_deref(), Some("Hello, Slack from Rust!"));
+
+        // Invalid messages are still rejected
+        let post = client.post_message(ChatPostMessageArguments::builder("C0000000000").build());
+        assert!(matches!(post, Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error");
+    }
+
+    #[test]
+    fn chat_dry_run_sends_no_writes() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })))
+                .expect(0)
+                .mount(&server)
+        );
+
+        let client = SlackClient { base_url: server.uri(), ..SlackClient::with_dry_run("xoxb-test".to_string()) };
+        let arguments = || ChatPostMessageArguments::builder("C0000000000").text("Deploy started").build();
+
+        // A second upsert updates the synthetic message instead of sending chat.update
+        assert_eq!(client.upsert_message("deploy", arguments()).unwrap(), DRY_RUN_TS);
+        assert_eq!(client.upsert_message("deploy", arguments()).unwrap(), DRY_RUN_TS);
+
+        assert!(client.post_ephemeral(arguments(), "U0000000000").is_ok());
+        let post_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64 + 3600;
+        assert!(client.schedule_message(arguments(), post_at).is_ok());
+        assert_eq!(client.delete("C0000000000".to_string(), DRY_RUN_TS.to_string()), Ok(()));
+    }
+
+    #[test]
+    fn chat_dry_run_records_payload() {
+        let client = SlackClient::with_dry_run("xoxb-test".to_string());
+        let arguments = ChatPostMessageArguments::builder("C0000000000").text("Deploy started").build();
+        assert_eq!(client.post_message(arguments), Ok(DRY_RUN_TS.to_string()));
+
+        // The JSON that would have been posted is reachable without the tracing feature
+        let requests = client.dry_run_requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].0, "chat.postMessage");
+        assert_eq!((requests[0].1["channel"].as_str(), requests[0].1["text"].as_str()), (Some("C0000000000"), Some("Deploy started")));
+    }
+
+    #[test]
+    fn chat_post_message_arguments_builder() {
+        let arguments = ChatPostMessageArguments::builder("C0000000000")
+            .text("Hello, Slack from Rust!")
+            .thread_ts("1700000000.000100")
+            .icon_emoji(":crab:")
+            .username("rustslack")
+            .build();
+
+        assert_eq!(arguments.channel, "C0000000000");
+        assert_eq!(arguments.text.as_deref(), Some("Hello, Slack from Rust!"));
+        assert_eq!(arguments.thread_ts.as_deref(), Some("1700000000.000100"));
+        assert_eq!(arguments.icon_emoji.as_deref(), Some(":crab:"));
+        assert_eq!(arguments.username.as_deref(), Some("rustslack"));
+        assert!(arguments.blocks.is_none());
+    }
+
+    #[test]
+    fn chat_post_message_unfurl_flags() {
+        let arguments = ChatPostMessageArguments::builder("C0000000000")
+            .text("Hello, Slack from Rust!")
+            .build();
+        let value = serde_json::to_value(arguments).unwrap();
+        assert!(value.get("unfurl_links").is_none());
+        assert!(value.get("unfurl_media").is_none());
+
+        // Unfurling is only sent when set explicitly
+        let arguments = ChatPostMessageArguments::builder("C0000000000")
+            .text("Hello, Slack from Rust!")
+            .unfurl_links(false)
+            .unfurl_media(false)
+            .build();
+        let value = serde_json::to_value(arguments).unwrap();
+        assert_eq!(value["unfurl_links"], json!(false));
+        assert_eq!(value["unfurl_media"], json!(false));
+    }
+
+    #[test]
+    fn chat_post_message_rejects_too_many_blocks_and_attachments() {
+        let client = SlackClient::new("xoxb-test".to_string());
+
+        let arguments = ChatPostMessageArguments::builder("C0000000000")
+            .blocks_typed(vec![Block::divider(); MAX_BLOCKS + 1])
+            .build();
+        assert_eq!(client.post_message(arguments).unwrap_err(), SlackApiError::InvalidArgument("blocks exceeds 50".into()));
+
+        let arguments = ChatPostMessageArguments::builder("C0000000000")
+            .attachments(vec![ChatPostMessageAttachment::default(); MAX_ATTACHMENTS + 1])
+            .build();
+        assert_eq!(client.post_message(arguments).unwrap_err(), SlackApiError::InvalidArgument("attachments exceeds 20".into()));
+    }
+
+    #[test]
+    fn chat_post_message_rejects_too_long_text() {
+        let client = SlackClient::new("xoxb-test".to_string());
+
+        // Slack would silently truncate the text
+        let post = client.post_message_text("C0000000000".to_string(), "a".repeat(MAX_TEXT_LEN + 1));
+        assert_eq!(post.unwrap_err(), SlackApiError::InvalidArgument("text exceeds 40000 characters".into()));
+    }
+
+    #[test]
+    fn chat_post_message_rejects_invalid_thread_ts() {
+        let client = SlackClient::new("xoxb-test".to_string());
+
+        for thread_ts in ["1.7e9", "1700000000", "1700000000.", ".000100", "C0000000000"] {
+            let arguments = ChatPostMessageArguments::builder("C0000000000")
+                .text("Hello, Slack from Rust!")
+                .thread_ts(thread_ts)
+                .build();
+
+            // Posting with a malformed thread_ts is rejected locally
+            let post = client.post_message(arguments);
+            assert!(matches!(post, Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error for {}", thread_ts);
+        }
+
+        assert!(is_valid_ts("1700000000.000100"));
+    }
+
+    #[test]
+    fn chat_post_message_response_warning() {
+        let body = serde_json::json!({
+            "ok": true,
+            "channel": "C0000000000",
+            "ts": "1700000000.000100",
+            "message": { "text": "Hello, Slack from Rust!" },
+            "warning": "missing_charset",
+        });
+
+        let response: ChatPostMessageResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(response.warning.as_deref(), Some("missing_charset"));
+        assert_eq!(response.warnings(), vec!["missing_charset"]);
+
+        // Legacy attachment fields are reported alongside other warnings
+        let body = serde_json::json!({
+            "ok": true,
+            "channel": "C0000000000",
+            "ts": "1700000000.000100",
+            "warning": "deprecated_argument,missing_charset",
+            "response_metadata": { "warnings": ["deprecated_argument", "missing_charset"] },
+        });
+        let response: ChatPostMessageResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(response.warnings(), vec!["deprecated_argument", "missing_charset"]);
+    }
+
+    #[test]
+    fn chat_post_message_full_captures_headers() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .respond_with(ResponseTemplate::new(200)
+                    .insert_header("x-slack-req-id", "0123456789abcdef")
+                    .insert_header("x-oauth-scopes", "chat:write,channels:read")
+                    .insert_header("x-powered-by", "Slack")
+                    .set_body_json(json!({
+                        "ok": true,
+                        "channel": "C0000000000",
+                        "ts": "1700000000.000100",
+                        "message": { "text": "Hello, Slack from Rust!" },
+                    })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let arguments = ChatPostMessageArguments::builder("C0000000000")
+            .text("Hello, Slack from Rust!")
+            .build();
+        let response = client.post_message_full(arguments).unwrap();
+
+        // Only the selected headers are kept
+        assert_eq!(response.headers.get("x-slack-req-id").map(String::as_str), Some("0123456789abcdef"));
+        assert_eq!(response.headers.get("x-oauth-scopes").map(String::as_str), Some("chat:write,channels:read"));
+        assert!(!response.headers.contains_key("x-powered-by"));
+    }
+
+    #[test]
+    fn chat_post_ephemeral_requires_user() {
+        let client = SlackClient::new("xoxb-test".to_string());
+        let arguments = ChatPostMessageArguments::builder("C0000000000")
+            .text("Hello, Slack from Rust!")
+            .build();
+
+        // Posting an ephemeral message without a user is rejected locally
+        let post = client.post_ephemeral(arguments, String::new());
+        assert_eq!(post.unwrap_err(), SlackApiError::InvalidArgument("user is required".into()));
+    }
+
+    #[test]
+    fn chat_attachment_blocks() {
+        let attachment = ChatPostMessageAttachment {
+            color: Option::from("#36a64f".to_string()),
+            blocks: Option::from(vec![Value::from(Block::section(Text::mrkdwn("*Deployed*")))]),
+            ..Default::default()
+        };
+
+        // Blocks are serialized alongside the legacy fields
+        assert_eq!(serde_json::to_value(attachment).unwrap(), json!({
+            "color": "#36a64f",
+            "blocks": [{ "type": "section", "text": { "type": "mrkdwn", "text": "*Deployed*" } }],
+        }));
+    }
+
+    /// Serialize a value to JSON and back, checking that unset fields are skipped instead of sent as null.
+    fn assert_round_trip<T: Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug>(value: &T) {
+        let json = serde_json::to_string(value).unwrap();
+        assert!(!json.contains("null"), "Unexpected null in {}", json);
+        assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn chat_parse_serialization() {
+        let arguments = ChatPostMessageArguments::builder("C0000000000").text("Hello").parse(Parse::None).build();
+        assert_eq!(serde_json::to_value(&arguments).unwrap()["parse"], "none");
+
+        // Reading is case-insensitive, and unknown values are ignored
+        let arguments: ChatPostMessageArguments = serde_json::from_value(json!({ "channel": "C0000000000", "parse": "Full" })).unwrap();
+        assert_eq!(arguments.parse, Some(Parse::Full));
+        let arguments: ChatPostMessageArguments = serde_json::from_value(json!({ "channel": "C0000000000", "parse": "client" })).unwrap();
+        assert_eq!(arguments.parse, None);
+    }
+
+    #[test]
+    fn chat_metadata_serialization() {
+        let metadata = MessageMetadata::new("deploy_finished", json!({ "version": "1.2.0" }));
+        let arguments = ChatPostMessageArguments::builder("C0000000000").text("Deployed").metadata(metadata.clone()).build();
+        assert_eq!(serde_json::to_value(&arguments).unwrap()["metadata"], json!({ "event_type": "deploy_finished", "event_payload": { "version": "1.2.0" } }));
+
+        // The former array form is still read
+        let arguments: ChatPostMessageArguments = serde_json::from_value(json!({
+            "channel": "C0000000000",
+            "metadata": [{ "event_type": "deploy_finished", "event_payload": { "version": "1.2.0" } }],
+        })).unwrap();
+        assert_eq!(arguments.metadata, Some(metadata.clone()));
+        #[allow(deprecated)]
+        let migrated = MessageMetadata::from_values(vec![json!({ "event_type": "deploy_finished", "event_payload": { "version": "1.2.0" } })]);
+        assert_eq!(migrated, Some(metadata));
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn chat_local_post_at_follows_daylight_saving_time() {
+        let at = |date: &str| local_post_at(NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M").unwrap(), chrono_tz::America::New_York);
+
+        // 09:00 is 14:00 UTC in winter and 13:00 UTC in summer
+        assert_eq!(at("2024-01-15 09:00"), Ok(1705327200));
+        assert_eq!(at("2024-03-10 09:00"), Ok(1710075600));
+
+        // 02:30 is skipped when clocks go forward
+        assert!(matches!(at("2024-03-10 02:30"), Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error");
+    }
+
+    #[test]
+    fn chat_unfurl() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.unfurl"))
+                .and(body_partial_json(json!({ "unfurls": { "https://example.com/issues/1": { "text": "Issue 1" } } })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.unfurl"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "cannot_unfurl_url" })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let unfurl = client.unfurl("C0000000000", "1700000000.000100", json!({ "https://example.com/issues/1": { "text": "Issue 1" } }));
+        assert_eq!(unfurl, Ok(()));
+
+        // Slack refusing a URL returns a typed error
+        let unfurl = client.unfurl("C0000000000", "1700000000.000100", json!({ "https://example.com/private": { "text": "Private" } }));
+        assert_eq!(unfurl, Err(SlackApiError::CannotUnfurlUrl));
+
+        // Previews must be keyed by URL
+        let unfurl = client.unfurl("C0000000000", "1700000000.000100", json!([]));
+        assert!(matches!(unfurl, Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error");
+    }
+
+    #[test]
+    fn chat_snippet_text() {
+        let text = snippet_text("config <prod>", "a: 1\nb: 2\n", Some("yaml"));
+        assert_eq!(text, "*config &lt;prod&gt;* (yaml)\n```\na: 1\nb: 2\n```");
+
+        // Fences in the content do not close the code block, however many backticks they have
+        let text = snippet_text("README", "```rust\nfn main() {}\n```", None);
+        assert_eq!(text.matches("```").count(), 2);
+        let text = snippet_text("README", "````md\n``````\n````", None);
+        assert_eq!(text.matches("```").count(), 2);
+
+        // Mentions and links in the content are escaped, so they do not ping anyone
+        let text = snippet_text("env", "NOTIFY=<!here> & <@U0000000000>", None);
+        assert_eq!(text, "*env*\n```\nNOTIFY=&lt;!here&gt; &amp; &lt;@U0000000000&gt;\n```");
+
+        // Truncation does not cut an entity in half
+        let text = snippet_text("dump", &"<".repeat(MAX_TEXT_LEN), None);
+        assert!(text.contains("&lt;\n```\n… truncated "), "Expected the content to end with a whole entity");
+
+        // Long content is truncated with a notice after the code block, within the text limit
+        let text = snippet_text("dump", &"a".repeat(MAX_TEXT_LEN + 100), None);
+        assert!(text.chars().count() <= MAX_TEXT_LEN, "Expected the snippet to fit the text limit");
+        assert!(text.contains("a\n```\n… truncated ") && text.ends_with(" characters"), "Expected a truncation notice after the fence");
+
+        // A long title is cut off, so even an oversized title and content fit the limit
+        let title = "<".repeat(MAX_TEXT_LEN);
+        let text = snippet_text(&title, &"a".repeat(MAX_TEXT_LEN), Some(&title));
+        assert!(text.chars().count() <= MAX_TEXT_LEN, "Expected the snippet to fit the text limit");
+        assert!(text.starts_with(&format!("*{}…*", "&lt;".repeat(MAX_SNIPPET_TITLE_LEN - 1))), "Expected the title to be cut off");
+    }
+
+    #[test]
+    fn chat_parse_permalink() {
+        let parsed = parse_permalink("https://example.slack.com/archives/C0000000000/p1700000000000100");
+        assert_eq!(parsed, Ok(("C0000000000".to_string(), "1700000000.000100".to_string())));
+
+        // The permalink of a reply carries the thread in the query
+        let parsed = parse_permalink("https://example.slack.com/archives/C0000000000/p1700000000000200?thread_ts=1700000000.000100&cid=C0000000000");
+        assert_eq!(parsed, Ok(("C0000000000".to_string(), "1700000000.000200".to_string())));
+
+        for permalink in ["https://example.slack.com/archives/C0000000000", "https://example.slack.com/files/U0000000000/F0000000000/report.pdf", "https://example.slack.com/archives/C0000000000/p123"] {
+            assert!(matches!(parse_permalink(permalink), Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error for {}", permalink);
+        }
+    }
+
+    #[test]
+    fn chat_normalize_icon_emoji() {
+        let icon = |icon_emoji: &str| normalize_icon_emoji(ChatPostMessageArguments::builder("C0000000000").icon_emoji(icon_emoji).build()).icon_emoji;
+        assert_eq!(icon("rocket").as_deref(), Some(":rocket:"));
+        assert_eq!(icon(":rocket:").as_deref(), Some(":rocket:"));
+        assert_eq!(icon(" rocket: ").as_deref(), Some(":rocket:"));
+        assert_eq!(icon(":thumbsup::skin-tone-2:").as_deref(), Some(":thumbsup::skin-tone-2:"));
+    }
+
+    #[test]
+    fn chat_attachment_colors() {
+        let attachment = ChatPostMessageAttachment::danger().text("Deploy failed");
+        assert_eq!(serde_json::to_value(&attachment).unwrap(), json!({ "color": "danger", "text": "Deploy failed" }));
+        assert_eq!(ChatPostMessageAttachment::good().color.as_deref(), Some("good"));
+        assert_eq!(ChatPostMessageAttachment::warning().color.as_deref(), Some("warning"));
+    }
+
+    #[test]
+    fn chat_serde_round_trip() {
+        assert_round_trip(&ChatPostMessageArguments::builder("C0000000000").text("Hello, Slack from Rust!").build());
+        assert_round_trip(&ChatPostMessageArguments::builder("C0000000000")
+            .blocks_typed(vec![Block::header("Deployed"), Block::divider()])
+            .thread_ts("1700000000.000100")
+            .unfurl_links(false)
+            .parse(Parse::Full)
+            .build());
+
+        assert_round_trip(&ChatPostMessageAttachment::default());
+        assert_round_trip(&ChatPostMessageAttachment {
+            color: Option::from("#36a64f".to_string()),
+            title: Option::from("Deploy".to_string()),
+            fields: Option::from(vec![ChatPostMessageField {
+                title: "Environment".to_string(),
+                value: "production".to_string(),
+                short: true,
+            }]),
+            ts: Option::from(1700000000),
+            blocks: Option::from(vec![Value::from(Block::section(Text::mrkdwn("*Deployed*")))]),
+            mrkdwn_in: Option::from(vec!["text".to_string(), "pretext".to_string(), "fields".to_string()]),
+            ..Default::default()
+        });
+
+        assert_round_trip(&ChatUpdateArguments {
+            channel: "C0000000000".to_string(),
+            ts: "1700000000.000100".to_string(),
+            text: Option::from("Hello again, Slack from Rust!".to_string()),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn chat_update_requires_content() {
+        let client = SlackClient::new("xoxb-test".to_string());
+        let arguments = ChatUpdateArguments {
+            channel: "C0000000000".to_string(),
+            ts: "1700000000.000100".to_string(),
+            ..Default::default()
+        };
+
+        // Updating without text, attachments, or blocks is rejected locally
+        let update = client.update(arguments);
+        assert!(matches!(update, Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error");
+    }
+
+    #[test]
+    fn chat_post_message_update_and_delete() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Post a message to the channel
+        let post = client.post_message_text(channel_id.to_string(), "Hello, Slack from Rust!".to_string());
+        assert!(post.is_ok(), "Failed to post message");
+
+        // Update the message in the channel
+        let arguments = ChatUpdateArguments {
+            channel: channel_id.to_string(),
+            ts: post.unwrap(),
+            text: Option::from("Hello again, Slack from Rust!".to_string()),
+            ..Default::default()
+        };
+        let update = client.update(arguments);
+        assert!(update.is_ok(), "Failed to update message");
+
+        // Delete the message from the channel
+        let message_id = update.unwrap();
+        let delete = client.delete(channel_id, message_id);
+        assert!(delete.is_ok(), "Failed to delete message");
+    }
+
+    #[test]
+    fn chat_schedule_message_rejects_out_of_range_post_at() {
+        let client = SlackClient::new("xoxb-test".to_string());
+        let arguments = ChatPostMessageArguments {
+            channel: "C0000000000".to_string(),
+            text: Option::from("Hello, Slack from Rust!".to_string()),
+            ..Default::default()
+        };
+
+        // Scheduling a message in the past is rejected locally
+        let schedule = client.schedule_message(arguments.clone(), 0);
+        assert_eq!(schedule, Err(SlackApiError::InvalidArgument("post_at must be in the future".into())));
+
+        // So is scheduling a message more than 120 days ahead
+        let post_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64 + 121 * 24 * 60 * 60;
+        let schedule = client.schedule_message(arguments, post_at);
+        assert_eq!(schedule, Err(SlackApiError::InvalidArgument("post_at exceeds 120 day limit".into())));
+    }
+
+    #[test]
+    fn chat_schedule_message_and_delete() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+        let text = "Hello, Slack from Rust!";
+
+        let client = SlackClient::new(token.to_string());
+        let arguments = ChatPostMessageArguments {
+            channel: channel_id.to_string(),
+            text: Option::from(text.to_string()),
+            ..Default::default()
+        };
+
+        // Schedule a message an hour from now
+        let post_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64 + 3600;
+        let schedule = client.schedule_message(arguments, post_at);
+        assert!(schedule.is_ok(), "Failed to schedule message");
+
+        // Delete the scheduled message
+        let scheduled_message_id = schedule.unwrap();
+        let delete = client.delete_scheduled_message(channel_id, scheduled_message_id);
+        assert!(delete.is_ok(), "Failed to delete scheduled message");
+    }
+
+    #[test]
+    fn chat_delete_scheduled_message_invalid_id() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.deleteScheduledMessage"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "invalid_scheduled_message_id" })))
+                .mount(&server)
+        );
+
+        // An unknown or already posted message is reported with a typed error
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let delete = client.delete_scheduled_message("C0000000000".to_string(), "Q0000000000".to_string());
+        assert_eq!(delete.unwrap_err(), SlackApiError::InvalidScheduledMessageId);
+    }
+
+    #[test]
+    fn chat_blocking_call_inside_runtime_fails() {
+        let runtime = Arc::new(Runtime::new().unwrap());
+        let client = SlackClient::with_runtime("xoxb-test".to_string(), runtime.clone());
+
+        // Calling a blocking method from async code returns an error instead of panicking
+        let post = runtime.block_on(async { client.post_message_text("C0000000000".to_string(), "Hello, Slack from Rust!".to_string()) });
+        assert!(matches!(post, Err(SlackApiError::RuntimeError(_))), "Expected a runtime error");
+    }
+
+    #[test]
+    fn chat_post_message_get_permalink_and_delete() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Post a message to the channel
+        let post = client.post_message_text(channel_id.to_string(), "Hello, Slack from Rust!".to_string());
+        assert!(post.is_ok(), "Failed to post message");
+        let message_id = post.unwrap();
+
+        // Get the permalink of the message
+        let permalink = client.get_permalink(channel_id.to_string(), message_id.to_string());
+        assert!(permalink.is_ok(), "Failed to get permalink");
+        assert!(permalink.unwrap().starts_with("https://"), "Unexpected permalink");
+
+        // Delete the message, after which it has no permalink
+        let delete = client.delete(channel_id.to_string(), message_id.to_string());
+        assert!(delete.is_ok(), "Failed to delete message");
+        let permalink = client.get_permalink(channel_id, message_id);
+        assert!(matches!(permalink, Err(SlackApiError::MessageNotFound)), "Expected a message not found error");
+    }
+
+    #[test]
+    fn chat_list_scheduled_messages_follows_cursor() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.scheduledMessages.list"))
+                .and(body_string_contains("cursor=page2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "scheduled_messages": [{ "id": "Q2", "channel_id": "C0000000000", "post_at": 1900000100, "date_created": 1700000000 }],
+                    "response_metadata": { "next_cursor": "" },
+                })))
+                .with_priority(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.scheduledMessages.list"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "scheduled_messages": [{ "id": "Q1", "channel_id": "C0000000000", "post_at": 1900000000, "date_created": 1700000000 }],
+                    "response_metadata": { "next_cursor": "page2" },
+                })))
+                .mount(&server)
+        );
+
+        // Both pages are accumulated
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let list = client.list_scheduled_messages(Option::from("C0000000000".to_string()));
+        let ids: Vec<String> = list.unwrap().into_iter().map(|message| message.id).collect();
+        assert_eq!(ids, vec!["Q1", "Q2"]);
+    }
+
+    #[test]
+    fn chat_post_message_multi_reports_each_channel() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .and(body_string_contains("C0000000001"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "channel_not_found" })))
+                .with_priority(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true, "channel": "C0000000000", "ts": "1700000000.000100", "message": {} })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let arguments = ChatPostMessageArguments::builder("")
+            .text("Hello, Slack from Rust!")
+            .build();
+
+        // Partial failures are reported per channel
+        let results = client.post_message_multi(vec!["C0000000000".to_string(), "C0000000001".to_string()], arguments).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_deref().ok(), Some("1700000000.000100"));
+        assert_eq!(results[1].as_ref().err().and_then(|e| e.code()), Some("channel_not_found"));
+    }
+
+    #[test]
+    fn chat_post_message_full_and_delete() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+        let text = "Hello, Slack from Rust!";
+
+        let client = SlackClient::new(token.to_string());
+        let arguments = ChatPostMessageArguments {
+            channel: channel_id.to_string(),
+            text: Option::from(text.to_string()),
+            ..Default::default()
+        };
+
+        // Post a message to the channel
+        let post = client.post_message_full(arguments);
+        assert!(post.is_ok(), "Failed to post message");
+
+        // Check the response fields
+        let response = post.unwrap();
+        assert_eq!(response.channel, channel_id);
+        assert_eq!(response.message.text.as_deref(), Some(text));
+
+        // Delete the message from the channel
+        let delete = client.delete(response.channel, response.ts);
         assert!(delete.is_ok(), "Failed to delete message");
     }
 }
\ No newline at end of file
@@ -0,0 +1,1291 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::SlackApiError;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use crate::slack_client::block_on_current_thread;
+use crate::slack_client::{decode_response, next_cursor};
+use crate::SlackClient;
+
+/// Maximum number of messages requested per page of conversations.history.
+const HISTORY_PAGE_SIZE: usize = 200;
+
+/// Maximum number of member IDs requested per page of conversations.members.
+const MEMBERS_PAGE_SIZE: usize = 1000;
+/// Number of messages requested per page of conversations.replies.
+const REPLIES_PAGE_SIZE: usize = 200;
+
+/// Maximum number of characters Slack accepts in a channel topic or purpose.
+const MAX_TOPIC_LEN: usize = 250;
+
+/// Maximum number of characters Slack accepts in a channel name.
+const MAX_CHANNEL_NAME_LEN: usize = 80;
+
+/// A conversation (channel, private channel, DM, or group DM).
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Channel {
+    /// ID of the conversation.
+    pub id: String,
+    /// Name of the conversation. Empty for direct messages.
+    #[serde(default)]
+    pub name: String,
+    /// Whether the conversation is private.
+    #[serde(default)]
+    pub is_private: bool,
+    /// Whether the conversation is archived.
+    #[serde(default)]
+    pub is_archived: bool,
+    /// Whether the calling user or bot is a member of the conversation.
+    #[serde(default)]
+    pub is_member: bool,
+    /// Number of members of the conversation. Only returned by some methods, e.g. conversations.info.
+    #[serde(default)]
+    pub num_members: Option<u32>,
+    /// Topic of the conversation. Missing for direct messages.
+    #[serde(default)]
+    pub topic: Option<ChannelTopic>,
+}
+
+/// Topic of a conversation.
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct ChannelTopic {
+    /// Text of the topic.
+    #[serde(default)]
+    pub value: String,
+    /// ID of the user who set the topic.
+    #[serde(default)]
+    pub creator: String,
+    /// Unix timestamp of when the topic was set.
+    #[serde(default)]
+    pub last_set: i64,
+}
+
+/// A message in a conversation.
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Message {
+    /// Timestamp of the message.
+    pub ts: String,
+    /// ID of the user who sent the message. Missing for some bot and system messages.
+    #[serde(default)]
+    pub user: Option<String>,
+    /// Text of the message.
+    #[serde(default)]
+    pub text: String,
+    /// Timestamp of the parent message, if the message is part of a thread.
+    #[serde(default)]
+    pub thread_ts: Option<String>,
+    /// Who last edited the message and when, if it was edited.
+    #[serde(default)]
+    pub edited: Option<MessageEdited>,
+}
+
+/// The last edit of a message.
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct MessageEdited {
+    /// ID of the user who edited the message.
+    #[serde(default)]
+    pub user: String,
+    /// Timestamp of the edit.
+    #[serde(default)]
+    pub ts: String,
+}
+
+/// Conversations trait for the Slack API client.
+pub trait Conversations: Send + Sync {
+    /// Lists all channels in a Slack team, following pagination.
+    ///
+    /// `types` is a comma-separated list of any combination of `public_channel`, `private_channel`, `mpim`, `im`.
+    ///
+    /// <https://api.slack.com/methods/conversations.list>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn list(&self, types: Option<String>, limit: Option<u16>) -> Result<Vec<Channel>, SlackApiError> {
+        block_on_current_thread(self.list_async(types, limit))
+    }
+
+    /// Lists all channels in a Slack team, following pagination, asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.list>
+    fn list_async(&self, types: Option<String>, limit: Option<u16>) -> Pin<Box<dyn Future<Output=Result<Vec<Channel>, SlackApiError>> + Send + '_>>;
+
+    /// Fetches the metadata of a single conversation, e.g. to check whether it is archived before posting.
+    ///
+    /// <https://api.slack.com/methods/conversations.info>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn channel_info(&self, channel: impl Into<String>) -> Result<Channel, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.channel_info_async(channel))
+    }
+
+    /// Fetches the metadata of a single conversation asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.info>
+    fn channel_info_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Channel, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Fetches up to `limit` of the most recent messages of a conversation, newest first.
+    ///
+    /// `oldest` and `latest` optionally bound the messages by timestamp.
+    ///
+    /// <https://api.slack.com/methods/conversations.history>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn history(&self, channel: impl Into<String>, limit: u16, oldest: Option<String>, latest: Option<String>) -> Result<Vec<Message>, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.history_async(channel, limit, oldest, latest))
+    }
+
+    /// Fetches up to `limit` of the most recent messages of a conversation asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.history>
+    fn history_async(&self, channel: impl Into<String>, limit: u16, oldest: Option<String>, latest: Option<String>) -> Pin<Box<dyn Future<Output=Result<Vec<Message>, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Streams all messages of a conversation, newest first, fetching pages lazily as the stream is polled.
+    ///
+    /// `oldest` and `latest` optionally bound the messages by timestamp. The stream ends after the first error.
+    ///
+    /// <https://api.slack.com/methods/conversations.history>
+    fn history_stream(&self, channel: impl Into<String>, oldest: Option<String>, latest: Option<String>) -> Pin<Box<dyn Stream<Item=Result<Message, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Opens a direct message with one user, or a group direct message with several, and returns its channel ID.
+    ///
+    /// <https://api.slack.com/methods/conversations.open>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn open_dm(&self, user_ids: Vec<String>) -> Result<String, SlackApiError> {
+        block_on_current_thread(self.open_dm_async(user_ids))
+    }
+
+    /// Opens a direct message or group direct message asynchronously and returns its channel ID.
+    ///
+    /// <https://api.slack.com/methods/conversations.open>
+    fn open_dm_async(&self, user_ids: Vec<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>>;
+
+    /// Lists the user IDs of all members of a conversation, following pagination.
+    ///
+    /// <https://api.slack.com/methods/conversations.members>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn members(&self, channel: impl Into<String>) -> Result<Vec<String>, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.members_async(channel))
+    }
+
+    /// Lists the user IDs of all members of a conversation, following pagination, asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.members>
+    fn members_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Vec<String>, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Joins a public channel, e.g. before posting to it after a `not_in_channel` error.
+    ///
+    /// Returns `SlackApiError::IsArchived` for archived channels and `SlackApiError::MethodNotSupportedForChannelType`
+    /// for channels that cannot be joined, such as private channels and direct messages.
+    ///
+    /// <https://api.slack.com/methods/conversations.join>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn join(&self, channel: impl Into<String>) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.join_async(channel))
+    }
+
+    /// Joins a public channel asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.join>
+    fn join_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Sets the topic of a channel, returning the topic as updated by Slack.
+    ///
+    /// Topics are limited to 250 characters.
+    ///
+    /// <https://api.slack.com/methods/conversations.setTopic>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn set_topic(&self, channel: impl Into<String>, topic: impl Into<String>) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.set_topic_async(channel, topic))
+    }
+
+    /// Sets the topic of a channel asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.setTopic>
+    fn set_topic_async(&self, channel: impl Into<String>, topic: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Sets the purpose of a channel, returning the purpose as updated by Slack.
+    ///
+    /// Purposes are limited to 250 characters.
+    ///
+    /// <https://api.slack.com/methods/conversations.setPurpose>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn set_purpose(&self, channel: impl Into<String>, purpose: impl Into<String>) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.set_purpose_async(channel, purpose))
+    }
+
+    /// Sets the purpose of a channel asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.setPurpose>
+    fn set_purpose_async(&self, channel: impl Into<String>, purpose: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Fetches all messages of the thread of `thread_ts`, the parent message first, following pagination.
+    ///
+    /// <https://api.slack.com/methods/conversations.replies>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn replies(&self, channel: impl Into<String>, thread_ts: impl Into<String>) -> Result<Vec<Message>, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.replies_async(channel, thread_ts))
+    }
+
+    /// Fetches all messages of a thread, following pagination, asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.replies>
+    fn replies_async(&self, channel: impl Into<String>, thread_ts: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Vec<Message>, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Fetches the message of `ts` in the history of a conversation, including its `edited` metadata.
+    ///
+    /// Returns `None` if there is no message at `ts`, e.g. it was deleted. Thread replies are not part of
+    /// the history, use `replies` for them.
+    ///
+    /// <https://api.slack.com/methods/conversations.history>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn fetch_message(&self, channel: impl Into<String>, ts: impl Into<String>) -> Result<Option<Message>, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.fetch_message_async(channel, ts))
+    }
+
+    /// Fetches the message of `ts` in the history of a conversation asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.history>
+    fn fetch_message_async(&self, channel: impl Into<String>, ts: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Option<Message>, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Creates a public or private channel and returns it.
+    ///
+    /// The name is checked locally against Slack's rules: at most 80 characters, without uppercase letters,
+    /// spaces or periods. Returns `SlackApiError::NameTaken` if a channel with the name already exists.
+    ///
+    /// <https://api.slack.com/methods/conversations.create>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn create_channel(&self, name: impl Into<String>, is_private: bool) -> Result<Channel, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.create_channel_async(name, is_private))
+    }
+
+    /// Creates a public or private channel asynchronously and returns it.
+    ///
+    /// <https://api.slack.com/methods/conversations.create>
+    fn create_channel_async(&self, name: impl Into<String>, is_private: bool) -> Pin<Box<dyn Future<Output=Result<Channel, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Archives a channel.
+    ///
+    /// Returns `SlackApiError::AlreadyArchived` if the channel is already archived.
+    ///
+    /// <https://api.slack.com/methods/conversations.archive>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn archive(&self, channel: impl Into<String>) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.archive_async(channel))
+    }
+
+    /// Archives a channel asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.archive>
+    fn archive_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Unarchives a channel.
+    ///
+    /// Returns `SlackApiError::NotArchived` if the channel is not archived.
+    ///
+    /// <https://api.slack.com/methods/conversations.unarchive>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn unarchive(&self, channel: impl Into<String>) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.unarchive_async(channel))
+    }
+
+    /// Unarchives a channel asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.unarchive>
+    fn unarchive_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Lists a single page of up to `limit` channels, starting at `cursor`, and returns the cursor of the next page.
+    ///
+    /// The cursor is `None` on the last page. Storing it lets a job resume the listing where it stopped.
+    ///
+    /// <https://api.slack.com/methods/conversations.list>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    #[allow(clippy::type_complexity)]
+    fn list_page(&self, types: Option<String>, cursor: Option<String>, limit: u16) -> Result<(Vec<Channel>, Option<String>), SlackApiError> {
+        block_on_current_thread(self.list_page_async(types, cursor, limit))
+    }
+
+    /// Lists a single page of channels asynchronously, and returns the cursor of the next page.
+    ///
+    /// <https://api.slack.com/methods/conversations.list>
+    #[allow(clippy::type_complexity)]
+    fn list_page_async(&self, types: Option<String>, cursor: Option<String>, limit: u16) -> Pin<Box<dyn Future<Output=Result<(Vec<Channel>, Option<String>), SlackApiError>> + Send + '_>>;
+
+    /// Fetches a single page of up to `limit` messages of a conversation, newest first, starting at `cursor`, and
+    /// returns the cursor of the next page.
+    ///
+    /// `oldest` and `latest` optionally bound the messages by timestamp, as in `history`.
+    /// The cursor is `None` on the last page.
+    ///
+    /// <https://api.slack.com/methods/conversations.history>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    #[allow(clippy::type_complexity)]
+    fn history_page(&self, channel: impl Into<String>, cursor: Option<String>, limit: u16, oldest: Option<String>, latest: Option<String>) -> Result<(Vec<Message>, Option<String>), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.history_page_async(channel, cursor, limit, oldest, latest))
+    }
+
+    /// Fetches a single page of messages of a conversation asynchronously, and returns the cursor of the next page.
+    ///
+    /// <https://api.slack.com/methods/conversations.history>
+    #[allow(clippy::type_complexity)]
+    fn history_page_async(&self, channel: impl Into<String>, cursor: Option<String>, limit: u16, oldest: Option<String>, latest: Option<String>) -> Pin<Box<dyn Future<Output=Result<(Vec<Message>, Option<String>), SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Whether the calling user or bot is a member of a conversation, e.g. to check before posting
+    /// instead of handling the `not_in_channel` error of chat.postMessage.
+    ///
+    /// <https://api.slack.com/methods/conversations.info>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn is_member(&self, channel: impl Into<String>) -> Result<bool, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.is_member_async(channel))
+    }
+
+    /// Whether the calling user or bot is a member of a conversation asynchronously.
+    ///
+    /// <https://api.slack.com/methods/conversations.info>
+    fn is_member_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<bool, SlackApiError>> + Send + '_>> where Self: Sized;
+}
+
+/// Implement the Conversations trait for SlackClient.
+impl Conversations for SlackClient {
+    /// Lists all channels in a Slack team.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn list(&self, types: Option<String>, limit: Option<u16>) -> Result<Vec<Channel>, SlackApiError> {
+        self.block_on(self.list_async(types, limit))
+    }
+
+    /// Lists all channels in a Slack team asynchronously.
+    fn list_async(&self, types: Option<String>, limit: Option<u16>) -> Pin<Box<dyn Future<Output=Result<Vec<Channel>, SlackApiError>> + Send + '_>> {
+        Box::pin(async move {
+            let mut channels = Vec::new();
+            let mut cursor: Option<String> = None;
+
+            loop {
+                let (page, next) = self.channels_page(&types, cursor, limit).await?;
+                channels.extend(page);
+
+                // Follow the cursor until there are no more pages
+                cursor = next;
+                if cursor.is_none() {
+                    break;
+                }
+            }
+
+            Ok(channels)
+        })
+    }
+
+    /// Fetches the metadata of a single conversation.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn channel_info(&self, channel: impl Into<String>) -> Result<Channel, SlackApiError> {
+        self.block_on(self.channel_info_async(channel))
+    }
+
+    /// Fetches the metadata of a single conversation asynchronously.
+    fn channel_info_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Channel, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        Box::pin(async move {
+            let query = [("channel", channel), ("include_num_members", "true".to_string())];
+            let body = self.get_query("conversations.info", &query).await?;
+
+            decode_response(body["channel"].clone())
+        })
+    }
+
+    /// Fetches the most recent messages of a conversation.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn history(&self, channel: impl Into<String>, limit: u16, oldest: Option<String>, latest: Option<String>) -> Result<Vec<Message>, SlackApiError> {
+        self.block_on(self.history_async(channel, limit, oldest, latest))
+    }
+
+    /// Fetches the most recent messages of a conversation asynchronously.
+    fn history_async(&self, channel: impl Into<String>, limit: u16, oldest: Option<String>, latest: Option<String>) -> Pin<Box<dyn Future<Output=Result<Vec<Message>, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        Box::pin(async move {
+            let limit = limit as usize;
+            let mut messages = Vec::new();
+            let mut cursor: Option<String> = None;
+
+            while messages.len() < limit {
+                let mut query = vec![
+                    ("channel", channel.clone()),
+                    ("limit", (limit - messages.len()).min(HISTORY_PAGE_SIZE).to_string()),
+                ];
+                if let Some(oldest) = &oldest {
+                    query.push(("oldest", oldest.clone()));
+                }
+                if let Some(latest) = &latest {
+                    query.push(("latest", latest.clone()));
+                }
+                if let Some(cursor) = &cursor {
+                    query.push(("cursor", cursor.clone()));
+                }
+
+                let body = self.get_query("conversations.history", &query).await?;
+                let page: Vec<Message> = decode_response(body["messages"].clone())?;
+                messages.extend(page);
+
+                // Follow the cursor until the limit is reached or there are no more pages
+                cursor = next_cursor(&body);
+                if cursor.is_none() {
+                    break;
+                }
+            }
+
+            messages.truncate(limit);
+            Ok(messages)
+        })
+    }
+
+    /// Streams all messages of a conversation.
+    fn history_stream(&self, channel: impl Into<String>, oldest: Option<String>, latest: Option<String>) -> Pin<Box<dyn Stream<Item=Result<Message, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        // The state is the cursor of the next page, or None once the last page was fetched
+        let pages = stream::try_unfold(Some(None), move |cursor: Option<Option<String>>| {
+            let mut query = vec![
+                ("channel", channel.clone()),
+                ("limit", HISTORY_PAGE_SIZE.to_string()),
+            ];
+            if let Some(oldest) = &oldest {
+                query.push(("oldest", oldest.clone()));
+            }
+            if let Some(latest) = &latest {
+                query.push(("latest", latest.clone()));
+            }
+
+            async move {
+                let Some(cursor) = cursor else {
+                    return Ok::<_, SlackApiError>(None);
+                };
+                if let Some(cursor) = cursor {
+                    query.push(("cursor", cursor));
+                }
+
+                let body = self.get_query("conversations.history", &query).await?;
+                let page: Vec<Message> = decode_response(body["messages"].clone())?;
+                Ok(Some((page, next_cursor(&body).map(Some))))
+            }
+        });
+
+        Box::pin(pages.map_ok(|page| stream::iter(page.into_iter().map(Ok))).try_flatten())
+    }
+
+    /// Opens a direct message or group direct message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn open_dm(&self, user_ids: Vec<String>) -> Result<String, SlackApiError> {
+        self.block_on(self.open_dm_async(user_ids))
+    }
+
+    /// Opens a direct message or group direct message asynchronously.
+    fn open_dm_async(&self, user_ids: Vec<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+
+        // Check if at least one user is provided
+        if user_ids.is_empty() {
+            return Box::pin(async { Err(SlackApiError::InvalidArgument("at least one user ID is required".into())) });
+        }
+
+        Box::pin(async move {
+            let users = user_ids.join(",");
+            let body = self.post_form("conversations.open", &[("users", &users)]).await?;
+
+            // Extract the channel ID from the JSON
+            let channel_id = body["channel"]["id"].as_str().ok_or(SlackApiError::InvalidArgument("No channel ID in response".into()))?.to_string();
+
+            Ok(channel_id)
+        })
+    }
+
+    /// Lists the user IDs of all members of a conversation.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn members(&self, channel: impl Into<String>) -> Result<Vec<String>, SlackApiError> {
+        self.block_on(self.members_async(channel))
+    }
+
+    /// Lists the user IDs of all members of a conversation asynchronously.
+    fn members_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Vec<String>, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        Box::pin(async move {
+            let mut members = Vec::new();
+            let mut cursor: Option<String> = None;
+
+            loop {
+                let mut query = vec![
+                    ("channel", channel.clone()),
+                    ("limit", MEMBERS_PAGE_SIZE.to_string()),
+                ];
+                if let Some(cursor) = &cursor {
+                    query.push(("cursor", cursor.clone()));
+                }
+
+                let body = self.get_query("conversations.members", &query).await?;
+                let page: Vec<String> = decode_response(body["members"].clone())?;
+                members.extend(page);
+
+                // Follow the cursor until there are no more pages
+                cursor = next_cursor(&body);
+                if cursor.is_none() {
+                    break;
+                }
+            }
+
+            Ok(members)
+        })
+    }
+
+    /// Joins a public channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn join(&self, channel: impl Into<String>) -> Result<(), SlackApiError> {
+        self.block_on(self.join_async(channel))
+    }
+
+    /// Joins a public channel asynchronously.
+    fn join_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        Box::pin(async move {
+            self.post_form("conversations.join", &[("channel", &channel)]).await?;
+            Ok(())
+        })
+    }
+
+    /// Sets the topic of a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn set_topic(&self, channel: impl Into<String>, topic: impl Into<String>) -> Result<String, SlackApiError> {
+        self.block_on(self.set_topic_async(channel, topic))
+    }
+
+    /// Sets the topic of a channel asynchronously.
+    fn set_topic_async(&self, channel: impl Into<String>, topic: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let topic: String = topic.into();
+
+        // Check the length locally, Slack truncates longer topics
+        if topic.chars().count() > MAX_TOPIC_LEN {
+            return Box::pin(async { Err(SlackApiError::InvalidArgument(format!("topic exceeds {} characters", MAX_TOPIC_LEN))) });
+        }
+
+        Box::pin(async move { self.set_channel_text("conversations.setTopic", "topic", &channel, &topic).await })
+    }
+
+    /// Sets the purpose of a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn set_purpose(&self, channel: impl Into<String>, purpose: impl Into<String>) -> Result<String, SlackApiError> {
+        self.block_on(self.set_purpose_async(channel, purpose))
+    }
+
+    /// Sets the purpose of a channel asynchronously.
+    fn set_purpose_async(&self, channel: impl Into<String>, purpose: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let purpose: String = purpose.into();
+
+        // Check the length locally, Slack truncates longer purposes
+        if purpose.chars().count() > MAX_TOPIC_LEN {
+            return Box::pin(async { Err(SlackApiError::InvalidArgument(format!("purpose exceeds {} characters", MAX_TOPIC_LEN))) });
+        }
+
+        Box::pin(async move { self.set_channel_text("conversations.setPurpose", "purpose", &channel, &purpose).await })
+    }
+
+    /// Fetches all messages of a thread.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn replies(&self, channel: impl Into<String>, thread_ts: impl Into<String>) -> Result<Vec<Message>, SlackApiError> {
+        self.block_on(self.replies_async(channel, thread_ts))
+    }
+
+    /// Fetches all messages of a thread asynchronously.
+    fn replies_async(&self, channel: impl Into<String>, thread_ts: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Vec<Message>, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let thread_ts: String = thread_ts.into();
+
+        Box::pin(async move {
+            let mut messages = Vec::new();
+            let mut cursor: Option<String> = None;
+
+            loop {
+                let mut query = vec![
+                    ("channel", channel.clone()),
+                    ("ts", thread_ts.clone()),
+                    ("limit", REPLIES_PAGE_SIZE.to_string()),
+                ];
+                if let Some(cursor) = &cursor {
+                    query.push(("cursor", cursor.clone()));
+                }
+
+                let body = self.get_query("conversations.replies", &query).await?;
+                let page: Vec<Message> = decode_response(body["messages"].clone())?;
+                messages.extend(page);
+
+                // Follow the cursor while Slack reports more messages
+                cursor = next_cursor(&body);
+                if !body["has_more"].as_bool().unwrap_or(false) || cursor.is_none() {
+                    break;
+                }
+            }
+
+            Ok(messages)
+        })
+    }
+
+    /// Fetches a single message of a conversation.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn fetch_message(&self, channel: impl Into<String>, ts: impl Into<String>) -> Result<Option<Message>, SlackApiError> {
+        self.block_on(self.fetch_message_async(channel, ts))
+    }
+
+    /// Fetches a single message of a conversation asynchronously.
+    fn fetch_message_async(&self, channel: impl Into<String>, ts: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Option<Message>, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let ts: String = ts.into();
+
+        Box::pin(async move {
+            let query = [
+                ("channel", channel.as_str()),
+                ("latest", ts.as_str()),
+                ("limit", "1"),
+                ("inclusive", "true"),
+            ];
+            let body = self.get_query("conversations.history", &query).await?;
+            let messages: Vec<Message> = decode_response(body["messages"].clone())?;
+
+            // The history returns the closest older message when the one at ts is gone
+            Ok(messages.into_iter().find(|message| message.ts == ts))
+        })
+    }
+
+    /// Creates a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn create_channel(&self, name: impl Into<String>, is_private: bool) -> Result<Channel, SlackApiError> {
+        self.block_on(self.create_channel_async(name, is_private))
+    }
+
+    /// Creates a channel asynchronously.
+    fn create_channel_async(&self, name: impl Into<String>, is_private: bool) -> Pin<Box<dyn Future<Output=Result<Channel, SlackApiError>> + Send + '_>> {
+        let name: String = name.into();
+
+        if let Err(e) = validate_channel_name(&name) {
+            return Box::pin(async { Err(e) });
+        }
+
+        Box::pin(async move {
+            let body = self.post_form("conversations.create", &[("name", name), ("is_private", is_private.to_string())]).await?;
+
+            decode_response(body["channel"].clone())
+        })
+    }
+
+    /// Archives a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn archive(&self, channel: impl Into<String>) -> Result<(), SlackApiError> {
+        self.block_on(self.archive_async(channel))
+    }
+
+    /// Archives a channel asynchronously.
+    fn archive_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        Box::pin(async move {
+            self.post_form("conversations.archive", &[("channel", &channel)]).await?;
+            Ok(())
+        })
+    }
+
+    /// Unarchives a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn unarchive(&self, channel: impl Into<String>) -> Result<(), SlackApiError> {
+        self.block_on(self.unarchive_async(channel))
+    }
+
+    /// Unarchives a channel asynchronously.
+    fn unarchive_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        Box::pin(async move {
+            self.post_form("conversations.unarchive", &[("channel", &channel)]).await?;
+            Ok(())
+        })
+    }
+
+    /// Lists a single page of channels.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn list_page(&self, types: Option<String>, cursor: Option<String>, limit: u16) -> Result<(Vec<Channel>, Option<String>), SlackApiError> {
+        self.block_on(self.list_page_async(types, cursor, limit))
+    }
+
+    /// Lists a single page of channels asynchronously.
+    fn list_page_async(&self, types: Option<String>, cursor: Option<String>, limit: u16) -> Pin<Box<dyn Future<Output=Result<(Vec<Channel>, Option<String>), SlackApiError>> + Send + '_>> {
+        Box::pin(async move { self.channels_page(&types, cursor, Some(limit)).await })
+    }
+
+    /// Fetches a single page of messages of a conversation.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn history_page(&self, channel: impl Into<String>, cursor: Option<String>, limit: u16, oldest: Option<String>, latest: Option<String>) -> Result<(Vec<Message>, Option<String>), SlackApiError> {
+        self.block_on(self.history_page_async(channel, cursor, limit, oldest, latest))
+    }
+
+    /// Fetches a single page of messages of a conversation asynchronously.
+    fn history_page_async(&self, channel: impl Into<String>, cursor: Option<String>, limit: u16, oldest: Option<String>, latest: Option<String>) -> Pin<Box<dyn Future<Output=Result<(Vec<Message>, Option<String>), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        Box::pin(async move {
+            let mut query = vec![("channel", channel), ("limit", limit.to_string())];
+            if let Some(oldest) = oldest {
+                query.push(("oldest", oldest));
+            }
+            if let Some(latest) = latest {
+                query.push(("latest", latest));
+            }
+            if let Some(cursor) = cursor {
+                query.push(("cursor", cursor));
+            }
+
+            let body = self.get_query("conversations.history", &query).await?;
+            let messages: Vec<Message> = decode_response(body["messages"].clone())?;
+
+            Ok((messages, next_cursor(&body)))
+        })
+    }
+
+    /// Whether the caller is a member of a conversation.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn is_member(&self, channel: impl Into<String>) -> Result<bool, SlackApiError> {
+        self.block_on(self.is_member_async(channel))
+    }
+
+    /// Whether the caller is a member of a conversation asynchronously.
+    fn is_member_async(&self, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<bool, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        Box::pin(async move {
+            let channel = self.channel_info_async(channel).await?;
+            Ok(channel.is_member)
+        })
+    }
+}
+
+/// Check a channel name against Slack's rules, so an invalid name fails before the request.
+fn validate_channel_name(name: &str) -> Result<(), SlackApiError> {
+    if name.is_empty() {
+        return Err(SlackApiError::InvalidArgument("channel name is required".into()));
+    }
+    if name.chars().count() > MAX_CHANNEL_NAME_LEN {
+        return Err(SlackApiError::InvalidArgument(format!("channel name exceeds {} characters", MAX_CHANNEL_NAME_LEN)));
+    }
+
+    // Names are lowercase letters, numbers, hyphens and underscores; letters of other scripts are allowed too
+    if let Some(c) = name.chars().find(|&c| c.is_uppercase() || c.is_whitespace() || (c.is_ascii() && !c.is_ascii_alphanumeric() && c != '-' && c != '_')) {
+        return Err(SlackApiError::InvalidArgument(format!("channel name contains invalid character {:?}", c)));
+    }
+
+    Ok(())
+}
+
+impl SlackClient {
+    /// Lists a single page of channels starting at `cursor`, and returns the cursor of the next page.
+    async fn channels_page(&self, types: &Option<String>, cursor: Option<String>, limit: Option<u16>) -> Result<(Vec<Channel>, Option<String>), SlackApiError> {
+        let mut query = Vec::new();
+        if let Some(types) = types {
+            query.push(("types", types.clone()));
+        }
+        if let Some(limit) = limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(cursor) = cursor {
+            query.push(("cursor", cursor));
+        }
+
+        let body = self.get_query("conversations.list", &query).await?;
+        let channels: Vec<Channel> = decode_response(body["channels"].clone())?;
+
+        Ok((channels, next_cursor(&body)))
+    }
+
+    /// Sets the `field` (topic or purpose) of a channel with `method`, and returns its new value from the updated channel.
+    async fn set_channel_text(&self, method: &str, field: &str, channel: &str, value: &str) -> Result<String, SlackApiError> {
+        let body = self.post_form(method, &[("channel", channel), (field, value)]).await?;
+
+        // Extract the new value from the channel in the JSON
+        let value = body["channel"][field]["value"].as_str().ok_or(SlackApiError::InvalidArgument(format!("No {} in response", field)))?.to_string();
+
+        Ok(value)
+    }
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod conversations_tests {
+    use std::env;
+
+    use serde_json::json;
+    use tokio::runtime::Runtime;
+    use wiremock::matchers::{body_string_contains, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn conversations_list() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // List the public and private channels
+        let list = client.list(Option::from("public_channel,private_channel".to_string()), Option::from(200));
+        assert!(list.is_ok(), "Failed to list channels");
+
+        let channels = list.unwrap();
+        assert!(channels.iter().any(|channel| channel.id == channel_id), "Channel not found in list");
+    }
+
+    #[test]
+    fn conversations_channel_info() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Fetch the channel the tests post to
+        let info = client.channel_info(channel_id.to_string());
+        assert!(info.is_ok(), "Failed to fetch channel info");
+
+        let channel = info.unwrap();
+        assert_eq!(channel.id, channel_id);
+        assert!(!channel.is_archived, "Channel is archived");
+    }
+
+    #[test]
+    fn conversations_channel_info_from_mock() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.info"))
+                .and(query_param("channel", "C0000000000"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": {
+                        "id": "C0000000000",
+                        "name": "alerts",
+                        "is_archived": true,
+                        "is_member": true,
+                        "num_members": 12,
+                        "topic": { "value": "Production alerts", "creator": "U0000000000", "last_set": 1700000000 },
+                    },
+                })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let channel = client.channel_info("C0000000000".to_string()).unwrap();
+        assert!(channel.is_archived);
+        assert!(channel.is_member);
+        assert_eq!(channel.num_members, Some(12));
+        assert_eq!(channel.topic.unwrap().value, "Production alerts");
+    }
+
+    #[test]
+    fn conversations_is_member() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.info"))
+                .and(query_param("channel", "C0000000001"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": { "id": "C0000000001", "name": "general", "is_member": true },
+                })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.info"))
+                .and(query_param("channel", "C0000000002"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": { "id": "C0000000002", "name": "random", "is_member": false },
+                })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        assert_eq!(client.is_member("C0000000001"), Ok(true));
+        assert_eq!(client.is_member("C0000000002"), Ok(false));
+    }
+
+    #[test]
+    fn conversations_history() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Read the last messages of the channel
+        let history = client.history(channel_id, 5, None, None);
+        assert!(history.is_ok(), "Failed to read history");
+        assert!(history.unwrap().len() <= 5, "Too many messages returned");
+    }
+
+    #[test]
+    fn conversations_history_stream_follows_cursor() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.history"))
+                .and(query_param("cursor", "page2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "messages": [{ "ts": "1700000000.000100", "text": "first" }],
+                })))
+                .with_priority(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.history"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "messages": [{ "ts": "1700000000.000300", "text": "third" }, { "ts": "1700000000.000200", "text": "second" }],
+                    "response_metadata": { "next_cursor": "page2" },
+                })))
+                .mount(&server)
+        );
+
+        // Messages of both pages are streamed in order
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let messages: Vec<Message> = runtime.block_on(client.history_stream("C0000000000".to_string(), None, None).try_collect()).unwrap();
+        let texts: Vec<&str> = messages.iter().map(|message| message.text.as_str()).collect();
+        assert_eq!(texts, vec!["third", "second", "first"]);
+    }
+
+    #[test]
+    fn conversations_members_follows_cursor() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.members"))
+                .and(query_param("cursor", "page2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "members": ["U0000000003"],
+                    "response_metadata": { "next_cursor": "" },
+                })))
+                .with_priority(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.members"))
+                .and(query_param("channel", "C0000000000"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "members": ["U0000000001", "U0000000002"],
+                    "response_metadata": { "next_cursor": "page2" },
+                })))
+                .mount(&server)
+        );
+
+        // Members of all pages are accumulated
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let members = client.members("C0000000000".to_string()).unwrap();
+        assert_eq!(members, vec!["U0000000001", "U0000000002", "U0000000003"]);
+    }
+
+    #[test]
+    fn conversations_join() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Joining a channel the bot is already in succeeds
+        let join = client.join(channel_id);
+        assert!(join.is_ok(), "Failed to join channel");
+    }
+
+    #[test]
+    fn conversations_join_typed_errors() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/conversations.join"))
+                .and(body_string_contains("C0000000001"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "is_archived" })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/conversations.join"))
+                .and(body_string_contains("D0000000001"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "method_not_supported_for_channel_type" })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        assert_eq!(client.join("C0000000001".to_string()).unwrap_err(), SlackApiError::IsArchived);
+        assert_eq!(client.join("D0000000001".to_string()).unwrap_err(), SlackApiError::MethodNotSupportedForChannelType);
+    }
+
+    #[test]
+    fn conversations_open_dm() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let user_id = env::var("SLACK_USER_ID").expect("Expected a user id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Open a direct message with the user
+        let open = client.open_dm(vec![user_id]);
+        assert!(open.is_ok(), "Failed to open direct message");
+        assert!(open.unwrap().starts_with('D'), "Unexpected direct message channel ID");
+    }
+
+    #[test]
+    fn conversations_open_dm_requires_users() {
+        let client = SlackClient::new("xoxb-test".to_string());
+
+        // Opening a direct message without users is rejected locally
+        let open = client.open_dm(Vec::new());
+        assert!(matches!(open, Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error");
+    }
+
+    #[test]
+    fn conversations_set_topic() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/conversations.setTopic"))
+                .and(body_string_contains("topic=Deploy%3A+green"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": { "id": "C0000000000", "topic": { "value": "Deploy: green", "creator": "U0000000000", "last_set": 1700000000 } },
+                })))
+                .expect(1)
+                .mount(&server)
+        );
+
+        // The topic is returned as Slack stored it
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        assert_eq!(client.set_topic("C0000000000", "Deploy: green").unwrap(), "Deploy: green");
+
+        // Topics over the limit are rejected locally
+        let topic = client.set_topic("C0000000000", "x".repeat(251));
+        assert_eq!(topic, Err(SlackApiError::InvalidArgument("topic exceeds 250 characters".into())));
+    }
+
+    #[test]
+    fn conversations_replies_follows_pagination() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.replies"))
+                .and(query_param("cursor", "page2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "messages": [{ "ts": "1700000000.000300", "user": "U0000000002", "text": "Fixed", "thread_ts": "1700000000.000100" }],
+                    "has_more": false,
+                })))
+                .with_priority(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.replies"))
+                .and(query_param("ts", "1700000000.000100"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "messages": [
+                        { "ts": "1700000000.000100", "user": "U0000000001", "text": "Build broken", "thread_ts": "1700000000.000100" },
+                        { "ts": "1700000000.000200", "user": "U0000000002", "text": "Looking", "thread_ts": "1700000000.000100" },
+                    ],
+                    "has_more": true,
+                    "response_metadata": { "next_cursor": "page2" },
+                })))
+                .mount(&server)
+        );
+
+        // The parent comes first, followed by the replies of every page
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let replies = client.replies("C0000000000", "1700000000.000100").unwrap();
+        let texts: Vec<&str> = replies.iter().map(|message| message.text.as_str()).collect();
+        assert_eq!(texts, vec!["Build broken", "Looking", "Fixed"]);
+    }
+
+    #[test]
+    fn conversations_fetch_message_reports_edits() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.history"))
+                .and(query_param("latest", "1700000000.000100"))
+                .and(query_param("limit", "1"))
+                .and(query_param("inclusive", "true"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "messages": [{
+                        "ts": "1700000000.000100",
+                        "user": "U0000000001",
+                        "text": "Deploy at 5pm",
+                        "edited": { "user": "U0000000001", "ts": "1700000100.000000" },
+                    }],
+                })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.history"))
+                .and(query_param("latest", "1700000000.000200"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "messages": [{ "ts": "1700000000.000100", "user": "U0000000001", "text": "Deploy at 5pm" }],
+                })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let message = client.fetch_message("C0000000000", "1700000000.000100").unwrap().unwrap();
+        assert_eq!(message.edited, Some(MessageEdited { user: "U0000000001".to_string(), ts: "1700000100.000000".to_string() }));
+
+        // An older message returned in place of a deleted one is not a match
+        let message = client.fetch_message("C0000000000", "1700000000.000200").unwrap();
+        assert_eq!(message, None);
+    }
+
+    #[test]
+    fn conversations_create_channel() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/conversations.create"))
+                .and(body_string_contains("name=customer-acme"))
+                .and(body_string_contains("is_private=true"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": { "id": "C0000000001", "name": "customer-acme", "is_private": true, "is_member": true },
+                })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/conversations.create"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "name_taken" })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let channel = client.create_channel("customer-acme", true).unwrap();
+        assert_eq!((channel.id.as_str(), channel.is_private), ("C0000000001", true));
+
+        // An existing name returns a typed error
+        let create = client.create_channel("general", false);
+        assert_eq!(create, Err(SlackApiError::NameTaken));
+
+        // Invalid names fail before the request
+        for name in ["", "Customer", "customer acme", "customer.acme", &"a".repeat(81)] {
+            assert!(matches!(client.create_channel(name, false), Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error for {:?}", name);
+        }
+        assert!(validate_channel_name("お客様_01").is_ok());
+    }
+
+    #[test]
+    fn conversations_archive_typed_errors() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        for (path_name, channel, error) in [("/conversations.archive", "C0000000001", "already_archived"), ("/conversations.unarchive", "C0000000002", "not_archived")] {
+            runtime.block_on(
+                Mock::given(method("POST"))
+                    .and(path(path_name))
+                    .and(body_string_contains(channel))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": error })))
+                    .mount(&server)
+            );
+            runtime.block_on(
+                Mock::given(method("POST"))
+                    .and(path(path_name))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })))
+                    .mount(&server)
+            );
+        }
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        assert_eq!(client.archive("C0000000000"), Ok(()));
+        assert_eq!(client.unarchive("C0000000000"), Ok(()));
+
+        // Repeating the change returns a typed error
+        assert_eq!(client.archive("C0000000001"), Err(SlackApiError::AlreadyArchived));
+        assert_eq!(client.unarchive("C0000000002"), Err(SlackApiError::NotArchived));
+    }
+
+    #[test]
+    fn conversations_list_page_returns_cursor() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.list"))
+                .and(query_param("cursor", "page2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channels": [{ "id": "C0000000002", "name": "random" }],
+                    "response_metadata": { "next_cursor": "" },
+                })))
+                .with_priority(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.list"))
+                .and(query_param("limit", "1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channels": [{ "id": "C0000000001", "name": "general" }],
+                    "response_metadata": { "next_cursor": "page2" },
+                })))
+                .mount(&server)
+        );
+
+        // Each page returns the cursor of the next one, until the last page
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let (channels, cursor) = client.list_page(None, None, 1).unwrap();
+        assert_eq!((channels[0].id.as_str(), cursor.as_deref()), ("C0000000001", Some("page2")));
+        let (channels, cursor) = client.list_page(None, cursor, 1).unwrap();
+        assert_eq!((channels[0].id.as_str(), cursor), ("C0000000002", None));
+    }
+
+    #[test]
+    fn conversations_history_page_within_bounds() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/conversations.history"))
+                .and(query_param("oldest", "1700000000.000000"))
+                .and(query_param("latest", "1700003600.000000"))
+                .and(query_param("cursor", "page2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "messages": [{ "ts": "1700000100.000000", "text": "Older" }],
+                    "response_metadata": { "next_cursor": "" },
+                })))
+                .mount(&server)
+        );
+
+        // The bounds are sent with every page
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let (messages, cursor) = client.history_page(
+            "C0000000000",
+            Some("page2".to_string()),
+            100,
+            Some("1700000000.000000".to_string()),
+            Some("1700003600.000000".to_string()),
+        ).unwrap();
+        assert_eq!((messages[0].text.as_str(), cursor), ("Older", None));
+    }
+}
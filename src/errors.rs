@@ -1,11 +1,143 @@
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
+
+use serde_json::Value;
 
 /// Error type for Slack API operations.
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum SlackApiError {
     InvalidArgument(String),
-    HttpRequestFailed(String),
+    /// The HTTP request of `method` failed.
+    HttpRequestFailed { method: String, message: String },
+    /// The request timed out, e.g. after the timeout given to `SlackClient::with_timeout`. Usually worth retrying.
+    Timeout { method: String, message: String },
+    /// No connection to Slack could be made, e.g. because of DNS or a refused connection. Usually worth retrying.
+    Connection { method: String, message: String },
+    /// The response body could not be read or decoded.
+    Decode { method: String, message: String },
+    /// The server answered with an HTTP error status, e.g. 401 or 403 from a proxy in front of Slack. `snippet` holds the start of the body.
+    HttpStatus { method: String, status: u16, snippet: String },
+    /// The underlying HTTP client could not be built.
+    ClientBuildFailed(String),
+    /// A blocking method could not be run on the client's runtime.
+    RuntimeError(String),
+    /// Slack returned `ok: false` with an error code from an API method, along with the raw response text.
+    SlackError { method: String, code: String, detail: Option<String> },
+    /// Slack returned `ok: false` from an API method with an explanation in `response_metadata.messages`, or without an
+    /// error code, along with the HTTP status and the entire parsed body.
+    ApiResponse { method: String, status: u16, body: Value },
+    /// The reaction has already been added to the message (`already_reacted`).
+    AlreadyReacted,
+    /// No user was found for the email address (`users_not_found`).
+    UsersNotFound,
+    /// No user was found for the ID (`user_not_found`).
+    UserNotFound,
+    /// The message does not exist (`message_not_found`).
+    MessageNotFound,
+    /// The token is invalid or revoked (`invalid_auth`).
+    InvalidAuth,
+    /// The message is already pinned to the channel (`already_pinned`).
+    AlreadyPinned,
+    /// The token lacks a scope needed by the method (`missing_scope`). `needed` is the scope to add to the app.
+    MissingScope { needed: String, provided: String },
+    /// Slack rejected the blocks of the message (`invalid_blocks`). `messages` explains which block and field is wrong.
+    InvalidBlocks { messages: Vec<String> },
+    /// The channel is archived (`is_archived`).
+    IsArchived,
+    /// The method cannot be used with this type of conversation, e.g. joining a private channel (`method_not_supported_for_channel_type`).
+    MethodNotSupportedForChannelType,
+    /// The scheduled message does not exist or has already been posted (`invalid_scheduled_message_id`).
+    InvalidScheduledMessageId,
+    /// The trigger ID of an interaction expired before the modal was opened (`expired_trigger_id`).
+    ExpiredTriggerId,
+    /// A channel with the name already exists (`name_taken`).
+    NameTaken,
+    /// Slack refused to unfurl one of the URLs (`cannot_unfurl_url`).
+    CannotUnfurlUrl,
+    /// The channel is already archived (`already_archived`).
+    AlreadyArchived,
+    /// The channel is not archived (`not_archived`).
+    NotArchived,
+    /// The response body was not a JSON object, e.g. an HTML error page during an outage. `snippet` holds the start of the body.
+    UnexpectedResponse { method: String, status: u16, snippet: String },
+    /// Slack rate limited the request (HTTP 429) and no retries were left, along with the delay it asked for.
+    RateLimited { method: String, retry_after: Duration },
+    /// The request was abandoned because its cancellation token was cancelled.
+    Cancelled,
+}
+
+impl SlackApiError {
+    /// Create an error from an `ok: false` response of `method`, using a dedicated variant for well-known error codes.
+    pub(crate) fn from_response(method: &str, status: u16, body: Value) -> Self {
+        match body["error"].as_str().unwrap_or_default() {
+            "already_reacted" => SlackApiError::AlreadyReacted,
+            "users_not_found" => SlackApiError::UsersNotFound,
+            "user_not_found" => SlackApiError::UserNotFound,
+            "message_not_found" => SlackApiError::MessageNotFound,
+            "invalid_auth" => SlackApiError::InvalidAuth,
+            "already_pinned" => SlackApiError::AlreadyPinned,
+            "invalid_scheduled_message_id" => SlackApiError::InvalidScheduledMessageId,
+            "expired_trigger_id" => SlackApiError::ExpiredTriggerId,
+            "name_taken" => SlackApiError::NameTaken,
+            "cannot_unfurl_url" => SlackApiError::CannotUnfurlUrl,
+            "already_archived" => SlackApiError::AlreadyArchived,
+            "not_archived" => SlackApiError::NotArchived,
+            "is_archived" => SlackApiError::IsArchived,
+            "method_not_supported_for_channel_type" => SlackApiError::MethodNotSupportedForChannelType,
+            "invalid_blocks" => SlackApiError::InvalidBlocks {
+                messages: body["response_metadata"]["messages"]
+                    .as_array()
+                    .map(|messages| messages.iter().filter_map(|message| message.as_str()).map(String::from).collect())
+                    .unwrap_or_default(),
+            },
+            "missing_scope" => SlackApiError::MissingScope {
+                needed: body["needed"].as_str().unwrap_or_default().to_string(),
+                provided: body["provided"].as_str().unwrap_or_default().to_string(),
+            },
+            // Keep the whole body when Slack explains the error, e.g. which field of the arguments is wrong
+            code if code.is_empty() || body["response_metadata"]["messages"].as_array().is_some_and(|messages| !messages.is_empty()) => {
+                SlackApiError::ApiResponse { method: method.to_string(), status, body }
+            }
+            code => SlackApiError::SlackError { method: method.to_string(), code: code.to_string(), detail: Some(body.to_string()) },
+        }
+    }
+
+    /// Set the method of a transport error converted from a reqwest error, which does not know it.
+    pub(crate) fn in_method(self, method: &str) -> Self {
+        match self {
+            SlackApiError::HttpRequestFailed { method: ref name, message } if name.is_empty() => SlackApiError::HttpRequestFailed { method: method.to_string(), message },
+            SlackApiError::Timeout { method: ref name, message } if name.is_empty() => SlackApiError::Timeout { method: method.to_string(), message },
+            SlackApiError::Connection { method: ref name, message } if name.is_empty() => SlackApiError::Connection { method: method.to_string(), message },
+            SlackApiError::Decode { method: ref name, message } if name.is_empty() => SlackApiError::Decode { method: method.to_string(), message },
+            err => err,
+        }
+    }
+
+    /// The Slack error code of the error, e.g. `channel_not_found`, if it came from an `ok: false` response.
+    pub fn code(&self) -> Option<&str> {
+        match *self {
+            SlackApiError::SlackError { ref code, .. } => Some(code),
+            SlackApiError::ApiResponse { ref body, .. } => body["error"].as_str(),
+            SlackApiError::AlreadyReacted => Some("already_reacted"),
+            SlackApiError::UsersNotFound => Some("users_not_found"),
+            SlackApiError::UserNotFound => Some("user_not_found"),
+            SlackApiError::MessageNotFound => Some("message_not_found"),
+            SlackApiError::InvalidAuth => Some("invalid_auth"),
+            SlackApiError::AlreadyPinned => Some("already_pinned"),
+            SlackApiError::InvalidScheduledMessageId => Some("invalid_scheduled_message_id"),
+            SlackApiError::ExpiredTriggerId => Some("expired_trigger_id"),
+            SlackApiError::NameTaken => Some("name_taken"),
+            SlackApiError::CannotUnfurlUrl => Some("cannot_unfurl_url"),
+            SlackApiError::AlreadyArchived => Some("already_archived"),
+            SlackApiError::NotArchived => Some("not_archived"),
+            SlackApiError::IsArchived => Some("is_archived"),
+            SlackApiError::MethodNotSupportedForChannelType => Some("method_not_supported_for_channel_type"),
+            SlackApiError::InvalidBlocks { .. } => Some("invalid_blocks"),
+            SlackApiError::MissingScope { .. } => Some("missing_scope"),
+            _ => None,
+        }
+    }
 }
 
 /// Implement the Error trait for SlackApiError.
@@ -16,14 +148,160 @@ impl fmt::Display for SlackApiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SlackApiError::InvalidArgument(ref msg) => write!(f, "Invalid argument: {}", msg),
-            SlackApiError::HttpRequestFailed(ref msg) => write!(f, "HTTP request failed: {}", msg),
+            SlackApiError::HttpRequestFailed { ref method, ref message } => write!(f, "{}HTTP request failed: {}", method_prefix(method), message),
+            SlackApiError::Timeout { ref method, ref message } => write!(f, "{}HTTP request timed out: {}", method_prefix(method), message),
+            SlackApiError::Connection { ref method, ref message } => write!(f, "{}HTTP connection failed: {}", method_prefix(method), message),
+            SlackApiError::Decode { ref method, ref message } => write!(f, "{}HTTP response decoding failed: {}", method_prefix(method), message),
+            SlackApiError::HttpStatus { ref method, status, ref snippet } => write!(f, "{}HTTP status {}: {}", method_prefix(method), status, snippet),
+            SlackApiError::ClientBuildFailed(ref msg) => write!(f, "HTTP client build failed: {}", msg),
+            SlackApiError::RuntimeError(ref msg) => write!(f, "Runtime error: {}", msg),
+            SlackApiError::SlackError { ref method, ref code, .. } => write!(f, "{}: {}", method, code),
+            SlackApiError::ApiResponse { ref method, ref body, .. } => write!(f, "{}: {}", method, body["error"].as_str().unwrap_or("unknown_error")),
+            SlackApiError::AlreadyReacted => write!(f, "Already reacted"),
+            SlackApiError::UsersNotFound => write!(f, "Users not found"),
+            SlackApiError::UserNotFound => write!(f, "User not found"),
+            SlackApiError::MessageNotFound => write!(f, "Message not found"),
+            SlackApiError::InvalidAuth => write!(f, "Invalid authentication token"),
+            SlackApiError::AlreadyPinned => write!(f, "Already pinned"),
+            SlackApiError::InvalidScheduledMessageId => write!(f, "Invalid scheduled message ID"),
+            SlackApiError::ExpiredTriggerId => write!(f, "Trigger ID expired"),
+            SlackApiError::NameTaken => write!(f, "Channel name already taken"),
+            SlackApiError::CannotUnfurlUrl => write!(f, "Cannot unfurl URL"),
+            SlackApiError::AlreadyArchived => write!(f, "Channel already archived"),
+            SlackApiError::NotArchived => write!(f, "Channel not archived"),
+            SlackApiError::IsArchived => write!(f, "Channel is archived"),
+            SlackApiError::MethodNotSupportedForChannelType => write!(f, "Method not supported for channel type"),
+            SlackApiError::InvalidBlocks { ref messages } => write!(f, "Invalid blocks: {}", messages.join("; ")),
+            SlackApiError::MissingScope { ref needed, ref provided } => write!(f, "Missing scope: needed {}, provided {}", needed, provided),
+            SlackApiError::UnexpectedResponse { ref method, status, ref snippet } => write!(f, "{}Unexpected response (HTTP {}): {}", method_prefix(method), status, snippet),
+            SlackApiError::RateLimited { ref method, ref retry_after } => write!(f, "{}Rate limited: retry after {} seconds", method_prefix(method), retry_after.as_secs()),
+            SlackApiError::Cancelled => write!(f, "Request cancelled"),
         }
     }
 }
 
+/// Prefix for the message of an error of `method`, e.g. `chat.postMessage: `, or nothing if the method is unknown.
+fn method_prefix(method: &str) -> String {
+    if method.is_empty() {
+        String::new()
+    } else {
+        format!("{}: ", method)
+    }
+}
+
 /// Implement the From trait for reqwest::Error to convert it into SlackApiError.
+///
+/// The method of the error is left empty, to be set with `in_method` where it is known.
 impl From<reqwest::Error> for SlackApiError {
     fn from(err: reqwest::Error) -> Self {
-        SlackApiError::HttpRequestFailed(err.to_string())
+        let method = String::new();
+        let message = err.to_string();
+        if err.is_timeout() {
+            return SlackApiError::Timeout { method, message };
+        }
+
+        // The fetch API of WASM targets does not tell connection failures apart
+        #[cfg(not(target_arch = "wasm32"))]
+        if err.is_connect() {
+            return SlackApiError::Connection { method, message };
+        }
+
+        if err.is_decode() {
+            SlackApiError::Decode { method, message }
+        } else {
+            SlackApiError::HttpRequestFailed { method, message }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod errors_tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn errors_display_includes_method() {
+        let err = SlackApiError::from_response("chat.postMessage", 200, json!({ "ok": false, "error": "channel_not_found" }));
+        assert_eq!(err.to_string(), "chat.postMessage: channel_not_found");
+        assert_eq!(err.code(), Some("channel_not_found"));
+    }
+
+    #[test]
+    fn errors_transport_display_includes_method() {
+        let err = SlackApiError::Timeout { method: String::new(), message: "operation timed out".to_string() }.in_method("chat.postMessage");
+        assert_eq!(err.to_string(), "chat.postMessage: HTTP request timed out: operation timed out");
+
+        // The method is kept once set
+        let err = err.in_method("chat.update");
+        assert_eq!(err.to_string(), "chat.postMessage: HTTP request timed out: operation timed out");
+
+        let err = SlackApiError::RateLimited { method: "chat.delete".to_string(), retry_after: Duration::from_secs(30) };
+        assert_eq!(err.to_string(), "chat.delete: Rate limited: retry after 30 seconds");
+    }
+
+    #[test]
+    fn errors_well_known_codes_are_typed() {
+        let err = SlackApiError::from_response("reactions.add", 200, json!({ "ok": false, "error": "already_reacted" }));
+        assert!(matches!(err, SlackApiError::AlreadyReacted));
+        assert_eq!(err.code(), Some("already_reacted"));
+    }
+
+    #[test]
+    fn errors_missing_scope() {
+        let err = SlackApiError::from_response("chat.postMessage", 200, json!({
+            "ok": false,
+            "error": "missing_scope",
+            "needed": "chat:write",
+            "provided": "channels:read,users:read",
+        }));
+        assert_eq!(err, SlackApiError::MissingScope { needed: "chat:write".to_string(), provided: "channels:read,users:read".to_string() });
+        assert_eq!(err.to_string(), "Missing scope: needed chat:write, provided channels:read,users:read");
+        assert_eq!(err.code(), Some("missing_scope"));
+    }
+
+    #[test]
+    fn errors_invalid_blocks() {
+        let err = SlackApiError::from_response("chat.postMessage", 200, json!({
+            "ok": false,
+            "error": "invalid_blocks",
+            "response_metadata": { "messages": ["[ERROR] must be more than 0 characters [json-pointer:/blocks/0/text/text]"] },
+        }));
+        assert_eq!(err, SlackApiError::InvalidBlocks {
+            messages: vec!["[ERROR] must be more than 0 characters [json-pointer:/blocks/0/text/text]".to_string()],
+        });
+        assert_eq!(err.code(), Some("invalid_blocks"));
+    }
+
+    #[test]
+    fn errors_are_comparable() {
+        assert_eq!(SlackApiError::InvalidArgument("user is empty".into()), SlackApiError::InvalidArgument("user is empty".into()));
+        assert_ne!(SlackApiError::InvalidArgument("user is empty".into()), SlackApiError::InvalidArgument("channel is empty".into()));
+
+        let err = SlackApiError::from_response("chat.postMessage", 200, json!({ "ok": false, "error": "channel_not_found" }));
+        assert_eq!(err, SlackApiError::SlackError {
+            method: "chat.postMessage".to_string(),
+            code: "channel_not_found".to_string(),
+            detail: Some(json!({ "ok": false, "error": "channel_not_found" }).to_string()),
+        });
+    }
+
+    #[test]
+    fn errors_api_response_keeps_body() {
+        let body = json!({
+            "ok": false,
+            "error": "invalid_arguments",
+            "response_metadata": { "messages": ["[ERROR] missing required field: channel"] },
+        });
+        let err = SlackApiError::from_response("chat.postMessage", 200, body.clone());
+        assert_eq!(err, SlackApiError::ApiResponse { method: "chat.postMessage".to_string(), status: 200, body });
+        assert_eq!(err.to_string(), "chat.postMessage: invalid_arguments");
+        assert_eq!(err.code(), Some("invalid_arguments"));
+
+        // Without an error code there is nothing to match but the body
+        let err = SlackApiError::from_response("chat.postMessage", 200, json!({ "ok": false }));
+        assert!(matches!(err, SlackApiError::ApiResponse { .. }), "Expected an API response error, got {:?}", err);
+        assert_eq!(err.code(), None);
     }
 }
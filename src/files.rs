@@ -0,0 +1,341 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::errors::SlackApiError;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use crate::slack_client::block_on_current_thread;
+use crate::slack_client::{assert_send, check_status, CAN_WAIT, DRY_RUN_TS};
+use crate::SlackClient;
+
+/// ID returned for files that were not uploaded because the client is in dry-run mode.
+const DRY_RUN_FILE_ID: &str = "F0000000000";
+
+/// Delays between the files.info requests waiting for a file to be shared, since Slack shares files asynchronously.
+const SHARE_POLL_DELAYS: [Duration; 5] = [
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_secs(2),
+    Duration::from_secs(4),
+];
+
+/// A message posted together with an uploaded file.
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct FileMessage {
+    /// Timestamp of the posted message.
+    pub ts: String,
+    /// ID of the uploaded file.
+    pub file_id: String,
+}
+
+/// Files trait for the Slack API client.
+pub trait Files: Send + Sync {
+    /// Uploads a file and shares it to a channel, returning the file ID.
+    ///
+    /// This uses the external upload flow: `files.getUploadURLExternal`, a POST of the raw bytes
+    /// to the returned URL, and `files.completeUploadExternal`.
+    ///
+    /// <https://api.slack.com/messaging/files#uploading_files>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn upload(&self, bytes: Vec<u8>, filename: impl Into<String>, channel: impl Into<String>) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.upload_async(bytes, filename, channel))
+    }
+
+    /// Uploads a file and shares it to a channel asynchronously, returning the file ID.
+    ///
+    /// <https://api.slack.com/messaging/files#uploading_files>
+    fn upload_async(&self, bytes: Vec<u8>, filename: impl Into<String>, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Uploads a file and shares it to a channel in a message with `text`, returning the message ts and file ID.
+    ///
+    /// The file is shared by files.completeUploadExternal with `text` as its `initial_comment`, so the message
+    /// shows the file itself. Slack shares the file asynchronously, so the ts of the message is read from the shares
+    /// of the file, polling files.info (which needs the `files:read` scope) for about 8 seconds until they appear.
+    /// On wasm32, which has no timer, files.info is polled without waiting in between.
+    /// If the file is still not shared by then, `SlackApiError::UnexpectedResponse` is returned even though the
+    /// message may yet be posted, so the call should not simply be retried.
+    ///
+    /// <https://api.slack.com/messaging/files#uploading_files>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_with_file(&self, channel: impl Into<String>, text: impl Into<String>, filename: impl Into<String>, bytes: Vec<u8>) -> Result<FileMessage, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.post_message_with_file_async(channel, text, filename, bytes))
+    }
+
+    /// Uploads a file and shares it to a channel in a message with `text` asynchronously.
+    ///
+    /// <https://api.slack.com/messaging/files#uploading_files>
+    fn post_message_with_file_async(&self, channel: impl Into<String>, text: impl Into<String>, filename: impl Into<String>, bytes: Vec<u8>) -> Pin<Box<dyn Future<Output=Result<FileMessage, SlackApiError>> + Send + '_>> where Self: Sized;
+}
+
+/// Implement the Files trait for SlackClient.
+impl Files for SlackClient {
+    /// Uploads a file and shares it to a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn upload(&self, bytes: Vec<u8>, filename: impl Into<String>, channel: impl Into<String>) -> Result<String, SlackApiError> {
+        self.block_on(self.upload_async(bytes, filename, channel))
+    }
+
+    /// Uploads a file and shares it to a channel asynchronously.
+    fn upload_async(&self, bytes: Vec<u8>, filename: impl Into<String>, channel: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let filename: String = filename.into();
+        let channel: String = channel.into();
+
+        // Check if the file has any content
+        if bytes.is_empty() {
+            return Box::pin(async { Err(SlackApiError::InvalidArgument("file is empty".into())) });
+        }
+
+        Box::pin(async move {
+            let (file_id, _) = self.upload_external(bytes, &filename, Some(&channel), None).await?;
+            Ok(file_id)
+        })
+    }
+
+    /// Uploads a file and shares it to a channel in a message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_with_file(&self, channel: impl Into<String>, text: impl Into<String>, filename: impl Into<String>, bytes: Vec<u8>) -> Result<FileMessage, SlackApiError> {
+        self.block_on(self.post_message_with_file_async(channel, text, filename, bytes))
+    }
+
+    /// Uploads a file and shares it to a channel in a message asynchronously.
+    fn post_message_with_file_async(&self, channel: impl Into<String>, text: impl Into<String>, filename: impl Into<String>, bytes: Vec<u8>) -> Pin<Box<dyn Future<Output=Result<FileMessage, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let text: String = text.into();
+        let filename: String = filename.into();
+
+        // Check if the file has any content
+        if bytes.is_empty() {
+            return Box::pin(async { Err(SlackApiError::InvalidArgument("file is empty".into())) });
+        }
+
+        Box::pin(async move {
+            // Share the file with the text as its comment, which Slack posts as a single message
+            let (file_id, body) = self.upload_external(bytes, &filename, Some(&channel), Some(&text)).await?;
+            if let Some(ts) = share_ts(&body["files"][0], &channel) {
+                return Ok(FileMessage { ts, file_id });
+            }
+
+            // The shares are usually missing from the response, so wait for them with files.info
+            for delay in SHARE_POLL_DELAYS {
+                if CAN_WAIT {
+                    tokio::time::sleep(delay).await;
+                }
+                let body = self.get_query("files.info", &[("file", file_id.as_str())]).await?;
+                if let Some(ts) = share_ts(&body["file"], &channel) {
+                    return Ok(FileMessage { ts, file_id });
+                }
+            }
+
+            Err(SlackApiError::UnexpectedResponse { method: "files.info".to_string(), status: 200, snippet: format!("File {} was not shared to {}", file_id, channel) })
+        })
+    }
+}
+
+impl SlackClient {
+    /// Uploads a file with the external upload flow, sharing it to `channel` with `initial_comment` if given, and
+    /// returns the file ID along with the response of files.completeUploadExternal.
+    async fn upload_external(&self, bytes: Vec<u8>, filename: &str, channel: Option<&str>, initial_comment: Option<&str>) -> Result<(String, Value), SlackApiError> {
+        // In dry-run mode, nothing is uploaded and the file gets a synthetic ID
+        if self.dry_run {
+            self.record_dry_run("files.completeUploadExternal", json!({
+                "filename": filename,
+                "length": bytes.len(),
+                "channel_id": channel,
+                "initial_comment": initial_comment,
+            }));
+
+            let shares = match channel {
+                Some(channel) => json!({ "public": { channel: [{ "ts": DRY_RUN_TS }] } }),
+                None => json!({}),
+            };
+            return Ok((DRY_RUN_FILE_ID.to_string(), json!({ "ok": true, "files": [{ "id": DRY_RUN_FILE_ID, "title": filename, "shares": shares }] })));
+        }
+
+        // Reserve an upload URL for the file
+        let length = bytes.len().to_string();
+        let body = self.post_form("files.getUploadURLExternal", &[("filename", filename), ("length", length.as_str())]).await?;
+        let upload_url = body["upload_url"].as_str().ok_or_else(|| missing_field("upload_url"))?.to_string();
+        let file_id = body["file_id"].as_str().ok_or_else(|| missing_field("file_id"))?.to_string();
+
+        // Upload the raw bytes to the reserved URL
+        let upload = self.client.post(&upload_url)
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(bytes);
+        assert_send(async { check_status("files.upload", self.execute(upload).await.map_err(|e| e.in_method("files.upload"))?).await }).await?;
+
+        // Complete the upload, sharing the file to the channel if there is one
+        let files = json!([{ "id": file_id, "title": filename }]).to_string();
+        let mut form = vec![("files", files.as_str())];
+        if let Some(channel) = channel {
+            form.push(("channel_id", channel));
+        }
+        if let Some(initial_comment) = initial_comment {
+            form.push(("initial_comment", initial_comment));
+        }
+        let body = self.post_form("files.completeUploadExternal", &form).await?;
+
+        Ok((file_id, body))
+    }
+}
+
+/// Report a files.getUploadURLExternal response without `field`.
+fn missing_field(field: &str) -> SlackApiError {
+    SlackApiError::UnexpectedResponse { method: "files.getUploadURLExternal".to_string(), status: 200, snippet: format!("No {} in response", field) }
+}
+
+/// Read the ts of the message sharing `file` to `channel` from its shares.
+fn share_ts(file: &Value, channel: &str) -> Option<String> {
+    let shares = &file["shares"];
+    ["public", "private"].into_iter()
+        .find_map(|visibility| shares[visibility][channel][0]["ts"].as_str())
+        .map(String::from)
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod files_tests {
+    use std::env;
+
+    use tokio::runtime::Runtime;
+    use wiremock::matchers::{body_string_contains, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn files_upload() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Upload a small text file to the channel
+        let upload = client.upload(b"Hello, Slack from Rust!".to_vec(), "hello.txt".to_string(), channel_id);
+        assert!(upload.is_ok(), "Failed to upload file");
+        assert!(upload.unwrap().starts_with('F'), "Unexpected file ID");
+    }
+
+    #[test]
+    fn files_upload_rejects_empty_file() {
+        let client = SlackClient::new("xoxb-test".to_string());
+
+        // Uploading an empty file is rejected locally
+        let upload = client.upload(Vec::new(), "empty.txt".to_string(), "C0000000000".to_string());
+        assert!(matches!(upload, Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error");
+    }
+
+    #[test]
+    fn files_post_message_with_file() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/files.getUploadURLExternal"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "upload_url": format!("{}/upload/F0000000000", server.uri()),
+                    "file_id": "F0000000000",
+                })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/upload/F0000000000"))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/files.completeUploadExternal"))
+                .and(body_string_contains("channel_id=C0000000000"))
+                .and(body_string_contains("initial_comment=Disk+almost+full"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "files": [{
+                        "id": "F0000000000",
+                        "shares": { "public": { "C0000000000": [{ "ts": "1700000000.000100", "channel_name": "alerts" }] } },
+                    }],
+                })))
+                .expect(1)
+                .mount(&server)
+        );
+
+        // The file is shared to the channel with the text as its comment, in a single message
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let post = client.post_message_with_file("C0000000000".to_string(), "Disk almost full".to_string(), "screenshot.png".to_string(), vec![0x89, 0x50, 0x4e, 0x47]);
+        assert_eq!(post.unwrap(), FileMessage { ts: "1700000000.000100".to_string(), file_id: "F0000000000".to_string() });
+    }
+
+    #[test]
+    fn files_post_message_with_file_waits_for_share() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/files.getUploadURLExternal"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "upload_url": format!("{}/upload/F0000000000", server.uri()),
+                    "file_id": "F0000000000",
+                })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/upload/F0000000000"))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/files.completeUploadExternal"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "files": [{ "id": "F0000000000", "shares": {} }],
+                })))
+                .expect(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/files.info"))
+                .and(query_param("file", "F0000000000"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "file": {
+                        "id": "F0000000000",
+                        "shares": { "private": { "C0000000000": [{ "ts": "1700000000.000100" }] } },
+                    },
+                })))
+                .expect(1)
+                .mount(&server)
+        );
+
+        // The share missing from the response is read from files.info once Slack has posted the message
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let post = client.post_message_with_file("C0000000000".to_string(), "Disk almost full".to_string(), "screenshot.png".to_string(), vec![0x89, 0x50, 0x4e, 0x47]);
+        assert_eq!(post.unwrap(), FileMessage { ts: "1700000000.000100".to_string(), file_id: "F0000000000".to_string() });
+    }
+
+    #[test]
+    fn files_dry_run_uploads_nothing() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })))
+                .expect(0)
+                .mount(&server)
+        );
+
+        let client = SlackClient { base_url: server.uri(), ..SlackClient::with_dry_run("xoxb-test".to_string()) };
+        let post = client.post_message_with_file("C0000000000".to_string(), "Disk almost full".to_string(), "screenshot.png".to_string(), vec![0x89, 0x50, 0x4e, 0x47]);
+        assert_eq!(post.unwrap(), FileMessage { ts: DRY_RUN_TS.to_string(), file_id: DRY_RUN_FILE_ID.to_string() });
+        assert_eq!(client.dry_run_requests()[0].1["initial_comment"], "Disk almost full");
+    }
+}
@@ -1,7 +1,48 @@
-pub use chat::{Chat, ChatPostMessageArguments, ChatPostMessageAttachment};
+#[cfg(all(not(target_arch = "wasm32"), not(any(feature = "native-tls", feature = "rustls-tls"))))]
+compile_error!("Enable a TLS backend with the `native-tls` or `rustls-tls` feature");
+#[cfg(all(not(target_arch = "wasm32"), feature = "native-tls", feature = "rustls-tls"))]
+compile_error!("Enable only one TLS backend; to use `rustls-tls`, disable the default features, which enable `native-tls`");
+
+pub use auth::{Auth, AuthTest};
+pub use blocks::{escape_mrkdwn, Block, Text};
+#[cfg(not(target_arch = "wasm32"))]
+pub use bulk::BulkPoster;
+pub use chat::{Chat, ChatPostMessageArguments, ChatPostMessageArgumentsBuilder, ChatPostMessageAttachment, ChatPostMessageResponse, ChatUpdateArguments, MAX_TEXT_LEN, MessageMetadata, Parse, PostedMessage, ScheduledMessage, ThreadHandle};
+pub use conversations::{Channel, ChannelTopic, Conversations, Message, MessageEdited};
+pub use errors::SlackApiError;
+pub use files::{FileMessage, Files};
+pub use message::MessageBuilder;
+#[cfg(feature = "mock")]
+pub use mock::MockChat;
+pub use pins::Pins;
+pub use reactions::{Reaction, Reactions};
+pub use retry::{RetryBudget, RetryPolicy};
+pub use shared_chat::{DynChat, SharedChat};
 pub use slack_client::SlackClient;
+pub use ts::Ts;
+pub use users::{User, Users};
+pub use views::Views;
+pub use webhook::{Webhook, WebhookPayload};
+pub use tokio_util::sync::CancellationToken;
 
 mod slack_client;
+mod auth;
+mod blocks;
+#[cfg(not(target_arch = "wasm32"))]
+mod bulk;
 mod chat;
+mod conversations;
 mod errors;
+mod files;
+mod message;
+#[cfg(feature = "mock")]
+mod mock;
+mod pins;
+mod reactions;
+mod retry;
+mod shared_chat;
+mod ts;
+mod users;
+mod views;
+mod webhook;
 
@@ -0,0 +1,144 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::blocks::{Block, Text};
+use crate::chat::{Chat, ChatPostMessageArguments, ChatPostMessageArgumentsBuilder};
+use crate::errors::SlackApiError;
+use crate::SlackClient;
+
+/// Builder of a Block Kit message that is posted with `send` or `send_async`, created by `SlackClient::message`.
+pub struct MessageBuilder<'a> {
+    client: &'a SlackClient,
+    arguments: ChatPostMessageArgumentsBuilder,
+    blocks: Vec<Block>,
+}
+
+impl<'a> MessageBuilder<'a> {
+    /// Set the fallback text shown in notifications and clients that cannot render blocks.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.arguments = self.arguments.text(text);
+        self
+    }
+
+    /// Post the message as a reply in the thread of `thread_ts`.
+    pub fn thread_ts(mut self, thread_ts: impl Into<String>) -> Self {
+        self.arguments = self.arguments.thread_ts(thread_ts);
+        self
+    }
+
+    /// Add a section block with text.
+    pub fn section(self, text: Text) -> Self {
+        self.block(Block::section(text))
+    }
+
+    /// Add a divider block.
+    pub fn divider(self) -> Self {
+        self.block(Block::divider())
+    }
+
+    /// Add a header block with plain text.
+    pub fn header(self, text: impl Into<String>) -> Self {
+        self.block(Block::header(text))
+    }
+
+    /// Add a context block.
+    pub fn context(self, elements: Vec<Text>) -> Self {
+        self.block(Block::context(elements))
+    }
+
+    /// Add any other block, e.g. section fields or actions.
+    pub fn block(mut self, block: Block) -> Self {
+        self.blocks.push(block);
+        self
+    }
+
+    /// Build the arguments of chat.postMessage from the text and blocks added so far.
+    pub fn build(self) -> ChatPostMessageArguments {
+        if self.blocks.is_empty() {
+            self.arguments.build()
+        } else {
+            self.arguments.blocks_typed(self.blocks).build()
+        }
+    }
+
+    /// Posts the message, and returns its ts.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub fn send(self) -> Result<String, SlackApiError> {
+        let client = self.client;
+        client.block_on(self.send_async())
+    }
+
+    /// Posts the message asynchronously, and returns its ts.
+    ///
+    /// <https://api.slack.com/methods/chat.postMessage>
+    pub fn send_async(self) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'a>> {
+        let client = self.client;
+        client.post_message_async(self.build())
+    }
+}
+
+impl SlackClient {
+    /// Start building a Block Kit message to `channel`.
+    pub fn message(&self, channel: impl Into<String>) -> MessageBuilder<'_> {
+        MessageBuilder {
+            client: self,
+            arguments: ChatPostMessageArguments::builder(channel),
+            blocks: Vec::new(),
+        }
+    }
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod message_tests {
+    use serde_json::json;
+    use tokio::runtime::Runtime;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn message_builder_sends_blocks() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .and(body_partial_json(json!({
+                    "channel": "C0000000000",
+                    "text": "Deploy finished",
+                    "blocks": [
+                        { "type": "header", "text": { "type": "plain_text", "text": "Deploy finished" } },
+                        { "type": "section", "text": { "type": "mrkdwn", "text": "*v1.2.0* is live" } },
+                        { "type": "divider" },
+                        { "type": "context", "elements": [{ "type": "plain_text", "text": "rustslack" }] },
+                    ],
+                })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true, "channel": "C0000000000", "ts": "1700000000.000100", "message": {} })))
+                .expect(1)
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let send = client.message("C0000000000")
+            .text("Deploy finished")
+            .header("Deploy finished")
+            .section(Text::mrkdwn("*v1.2.0* is live"))
+            .divider()
+            .context(vec![Text::plain_text("rustslack")])
+            .send();
+        assert_eq!(send, Ok("1700000000.000100".to_string()));
+    }
+
+    #[test]
+    fn message_builder_requires_content() {
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), "http://127.0.0.1:1".to_string());
+
+        // Without text or blocks the message fails before the request
+        let send = client.message("C0000000000").send();
+        assert!(matches!(send, Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error");
+    }
+}
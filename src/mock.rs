@@ -0,0 +1,520 @@
+use std::collections::HashMap;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+#[cfg(feature = "chrono-tz")]
+use chrono::NaiveDateTime;
+#[cfg(feature = "chrono-tz")]
+use chrono_tz::Tz;
+
+#[cfg(feature = "chrono-tz")]
+use crate::chat::local_post_at;
+use crate::chat::{parse_permalink, snippet_text, start_thread, validate_content, validate_post_message, validate_unfurls, Chat, ChatPostMessageArguments, ChatPostMessageResponse, ChatUpdateArguments, PostedMessage, ScheduledMessage, ThreadHandle};
+use crate::errors::SlackApiError;
+use crate::slack_client::unix_now;
+use crate::ts::Ts;
+
+/// In-memory implementation of the Chat trait for tests, recording the messages instead of sending them to Slack.
+///
+/// Messages are checked like the Slack client checks them, and get sequential timestamps starting at
+/// `1700000000.000001`.
+#[derive(Default, Debug)]
+pub struct MockChat {
+    default_channel: Option<String>,
+    counter: AtomicU64,
+    posted: Mutex<Vec<ChatPostMessageArguments>>,
+    updated: Mutex<Vec<ChatUpdateArguments>>,
+    deleted: Mutex<Vec<(String, String)>>,
+    scheduled: Mutex<Vec<ScheduledMessage>>,
+    posted_once: Mutex<HashMap<String, String>>,
+    upserted: Mutex<HashMap<String, (String, String)>>,
+}
+
+impl MockChat {
+    /// Create a new mock with nothing recorded.
+    pub fn new() -> Self {
+        MockChat::default()
+    }
+
+    /// Create a new mock with a default channel for `post_message_text_default`.
+    pub fn with_default_channel(channel: impl Into<String>) -> Self {
+        MockChat {
+            default_channel: Some(channel.into()),
+            ..MockChat::default()
+        }
+    }
+
+    /// The messages posted so far, including ephemeral messages and thread replies, in order.
+    pub fn posted_messages(&self) -> Vec<ChatPostMessageArguments> {
+        self.posted.lock().unwrap().clone()
+    }
+
+    /// The updates of messages made so far, in order.
+    pub fn updated_messages(&self) -> Vec<ChatUpdateArguments> {
+        self.updated.lock().unwrap().clone()
+    }
+
+    /// The channel and ts of the messages deleted so far, in order.
+    pub fn deleted_messages(&self) -> Vec<(String, String)> {
+        self.deleted.lock().unwrap().clone()
+    }
+
+    /// Return the next timestamp handed out for a message.
+    fn next_ts(&self) -> String {
+        format!("1700000000.{:06}", self.counter.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    /// Check and record a message, returning the response Slack would return for it.
+    fn post(&self, arguments: ChatPostMessageArguments) -> Result<ChatPostMessageResponse, SlackApiError> {
+        validate_post_message(&arguments)?;
+
+        let ts = self.next_ts();
+        let response = ChatPostMessageResponse {
+            ok: true,
+            channel: arguments.channel.clone(),
+            ts: ts.clone(),
+            message: PostedMessage {
+                ts: Some(ts),
+                text: arguments.text.clone(),
+                username: arguments.username.clone(),
+                blocks: arguments.blocks.clone(),
+                thread_ts: arguments.thread_ts.clone(),
+                ..Default::default()
+            },
+            warning: None,
+            headers: HashMap::new(),
+        };
+        self.posted.lock().unwrap().push(arguments);
+
+        Ok(response)
+    }
+
+    /// Check and record an update of a message, returning its ts.
+    fn update_message(&self, arguments: ChatUpdateArguments) -> Result<String, SlackApiError> {
+        validate_content(&arguments.text, &arguments.attachments, &arguments.blocks)?;
+
+        let ts = arguments.ts.clone();
+        self.updated.lock().unwrap().push(arguments);
+
+        Ok(ts)
+    }
+}
+
+/// Implement the Chat trait for MockChat.
+impl Chat for MockChat {
+    /// Records the deletion of a message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete(&self, channel: impl Into<String>, ts: impl Into<String>) -> Result<(), SlackApiError> {
+        futures::executor::block_on(self.delete_async(channel, ts))
+    }
+
+    /// Records the deletion of a message asynchronously.
+    fn delete_async(&self, channel: impl Into<String>, ts: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        self.deleted.lock().unwrap().push((channel.into(), ts.into()));
+        Box::pin(future::ready(Ok(())))
+    }
+
+    /// Records a message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message(&self, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.post_message_async(arguments))
+    }
+
+    /// Records a message asynchronously.
+    fn post_message_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        Box::pin(future::ready(self.post(arguments).map(|response| response.ts)))
+    }
+
+    /// Records a message, returning the full response.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_full(&self, arguments: ChatPostMessageArguments) -> Result<ChatPostMessageResponse, SlackApiError> {
+        futures::executor::block_on(self.post_message_full_async(arguments))
+    }
+
+    /// Records a message, returning the full response asynchronously.
+    fn post_message_full_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<ChatPostMessageResponse, SlackApiError>> + Send + '_>> {
+        Box::pin(future::ready(self.post(arguments)))
+    }
+
+    /// Records a message with text only.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_text(&self, channel: impl Into<String>, text: impl Into<String>) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.post_message_text_async(channel, text))
+    }
+
+    /// Records a message with text only asynchronously.
+    fn post_message_text_async(&self, channel: impl Into<String>, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        self.post_message_async(ChatPostMessageArguments::builder(channel).text(text).build())
+    }
+
+    /// Records a message with text only to the default channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_text_default(&self, text: impl Into<String>) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.post_message_text_default_async(text))
+    }
+
+    /// Records a message with text only to the default channel asynchronously.
+    fn post_message_text_default_async(&self, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let Some(channel) = self.default_channel.clone() else {
+            return Box::pin(future::ready(Err(SlackApiError::InvalidArgument("default_channel is not set".into()))));
+        };
+        self.post_message_text_async(channel, text)
+    }
+
+    /// Records a reply to a thread.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn reply_in_thread(&self, channel: impl Into<String>, thread_ts: impl Into<String>, text: impl Into<String>, broadcast: bool) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.reply_in_thread_async(channel, thread_ts, text, broadcast))
+    }
+
+    /// Records a reply to a thread asynchronously.
+    fn reply_in_thread_async(&self, channel: impl Into<String>, thread_ts: impl Into<String>, text: impl Into<String>, broadcast: bool) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let arguments = ChatPostMessageArguments {
+            channel: channel.into(),
+            text: Option::from(text.into()),
+            thread_ts: Option::from(thread_ts.into()),
+            reply_broadcast: broadcast.then_some(true),
+            ..Default::default()
+        };
+        self.post_message_async(arguments)
+    }
+
+    /// Records an update of a message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn update(&self, arguments: ChatUpdateArguments) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.update_async(arguments))
+    }
+
+    /// Records an update of a message asynchronously.
+    fn update_async(&self, arguments: ChatUpdateArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        Box::pin(future::ready(self.update_message(arguments)))
+    }
+
+    /// Records a scheduled message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn schedule_message(&self, arguments: ChatPostMessageArguments, post_at: i64) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.schedule_message_async(arguments, post_at))
+    }
+
+    /// Records a scheduled message asynchronously.
+    fn schedule_message_async(&self, arguments: ChatPostMessageArguments, post_at: i64) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        if let Err(e) = validate_post_message(&arguments) {
+            return Box::pin(future::ready(Err(e)));
+        }
+
+        let id = format!("Q{:010}", self.counter.fetch_add(1, Ordering::Relaxed) + 1);
+        self.scheduled.lock().unwrap().push(ScheduledMessage {
+            id: id.clone(),
+            channel_id: arguments.channel,
+            post_at,
+            date_created: unix_now().unwrap_or(0),
+        });
+        Box::pin(future::ready(Ok(id)))
+    }
+
+    /// Removes a scheduled message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete_scheduled_message(&self, channel: impl Into<String>, scheduled_message_id: impl Into<String>) -> Result<(), SlackApiError> {
+        futures::executor::block_on(self.delete_scheduled_message_async(channel, scheduled_message_id))
+    }
+
+    /// Removes a scheduled message asynchronously.
+    fn delete_scheduled_message_async(&self, channel: impl Into<String>, scheduled_message_id: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let scheduled_message_id: String = scheduled_message_id.into();
+
+        let mut scheduled = self.scheduled.lock().unwrap();
+        let Some(index) = scheduled.iter().position(|message| message.id == scheduled_message_id && message.channel_id == channel) else {
+            return Box::pin(future::ready(Err(SlackApiError::InvalidScheduledMessageId)));
+        };
+        scheduled.remove(index);
+        Box::pin(future::ready(Ok(())))
+    }
+
+    /// Returns a permalink in the form Slack uses.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn get_permalink(&self, channel: impl Into<String>, message_ts: impl Into<String>) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.get_permalink_async(channel, message_ts))
+    }
+
+    /// Returns a permalink in the form Slack uses asynchronously.
+    fn get_permalink_async(&self, channel: impl Into<String>, message_ts: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let permalink = format!("https://example.slack.com/archives/{}/p{}", channel.into(), message_ts.into().replace('.', ""));
+        Box::pin(future::ready(Ok(permalink)))
+    }
+
+    /// Records an ephemeral message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_ephemeral(&self, arguments: ChatPostMessageArguments, user: impl Into<String>) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.post_ephemeral_async(arguments, user))
+    }
+
+    /// Records an ephemeral message asynchronously.
+    fn post_ephemeral_async(&self, arguments: ChatPostMessageArguments, user: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        if user.into().is_empty() {
+            return Box::pin(future::ready(Err(SlackApiError::InvalidArgument("user is required".into()))));
+        }
+        self.post_message_async(arguments)
+    }
+
+    /// Lists the scheduled messages that have not been removed.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn list_scheduled_messages(&self, channel: Option<String>) -> Result<Vec<ScheduledMessage>, SlackApiError> {
+        futures::executor::block_on(self.list_scheduled_messages_async(channel))
+    }
+
+    /// Lists the scheduled messages that have not been removed asynchronously.
+    fn list_scheduled_messages_async(&self, channel: Option<String>) -> Pin<Box<dyn Future<Output=Result<Vec<ScheduledMessage>, SlackApiError>> + Send + '_>> {
+        let scheduled = self.scheduled.lock().unwrap()
+            .iter()
+            .filter(|message| channel.as_ref().is_none_or(|channel| message.channel_id == *channel))
+            .cloned()
+            .collect();
+        Box::pin(future::ready(Ok(scheduled)))
+    }
+
+    /// Records a message to each of several channels.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    #[allow(clippy::type_complexity)]
+    fn post_message_multi(&self, channels: Vec<String>, arguments: ChatPostMessageArguments) -> Result<Vec<Result<String, SlackApiError>>, SlackApiError> {
+        Ok(futures::executor::block_on(self.post_message_multi_async(channels, arguments)))
+    }
+
+    /// Records a message to each of several channels asynchronously.
+    #[allow(clippy::type_complexity)]
+    fn post_message_multi_async(&self, channels: Vec<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Vec<Result<String, SlackApiError>>> + Send + '_>> {
+        let results = channels.into_iter()
+            .map(|channel| self.post(ChatPostMessageArguments { channel, ..arguments.clone() }).map(|response| response.ts))
+            .collect();
+        Box::pin(future::ready(results))
+    }
+
+    /// Records the deletion of several messages.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    #[allow(clippy::type_complexity)]
+    fn delete_many(&self, channel: impl Into<String>, timestamps: Vec<String>) -> Result<Vec<Result<(), SlackApiError>>, SlackApiError> {
+        Ok(futures::executor::block_on(self.delete_many_async(channel, timestamps)))
+    }
+
+    /// Records the deletion of several messages asynchronously.
+    #[allow(clippy::type_complexity)]
+    fn delete_many_async(&self, channel: impl Into<String>, timestamps: Vec<String>) -> Pin<Box<dyn Future<Output=Vec<Result<(), SlackApiError>>> + Send + '_>> {
+        let channel: String = channel.into();
+
+        let mut deleted = self.deleted.lock().unwrap();
+        let results = timestamps.into_iter()
+            .map(|ts| deleted.push((channel.clone(), ts)))
+            .map(Ok)
+            .collect();
+        Box::pin(future::ready(results))
+    }
+
+    /// Records a /me message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_me_message(&self, channel: impl Into<String>, text: impl Into<String>) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.post_me_message_async(channel, text))
+    }
+
+    /// Records a /me message asynchronously.
+    fn post_me_message_async(&self, channel: impl Into<String>, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let text: String = text.into();
+
+        if text.is_empty() {
+            return Box::pin(future::ready(Err(SlackApiError::InvalidArgument("text is required".into()))));
+        }
+        self.post_message_text_async(channel, text)
+    }
+
+    /// Records a message unless one was already recorded with the same key.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_once(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.post_message_once_async(key, arguments))
+    }
+
+    /// Records a message unless one was already recorded with the same key asynchronously.
+    fn post_message_once_async(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let key: String = key.into();
+
+        let mut posted_once = self.posted_once.lock().unwrap();
+        if let Some(ts) = posted_once.get(&key) {
+            return Box::pin(future::ready(Ok(ts.clone())));
+        }
+        let result = self.post(arguments).map(|response| response.ts);
+        if let Ok(ts) = &result {
+            posted_once.insert(key, ts.clone());
+        }
+        Box::pin(future::ready(result))
+    }
+
+    /// Records a message unless the token is cancelled.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_cancellable(&self, arguments: ChatPostMessageArguments, cancel: CancellationToken) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.post_message_cancellable_async(arguments, cancel))
+    }
+
+    /// Records a message unless the token is cancelled asynchronously.
+    fn post_message_cancellable_async(&self, arguments: ChatPostMessageArguments, cancel: CancellationToken) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        if cancel.is_cancelled() {
+            return Box::pin(future::ready(Err(SlackApiError::Cancelled)));
+        }
+        self.post_message_async(arguments)
+    }
+
+    /// Records a message the first time a key is used, and an update of it afterwards.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn upsert_message(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.upsert_message_async(key, arguments))
+    }
+
+    /// Records a message the first time a key is used, and an update of it afterwards asynchronously.
+    fn upsert_message_async(&self, key: impl Into<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let key: String = key.into();
+
+        let mut upserted = self.upserted.lock().unwrap();
+        if let Some((channel, ts)) = upserted.get(&key).cloned() {
+            let update = ChatUpdateArguments {
+                channel,
+                ts,
+                text: arguments.text,
+                blocks: arguments.blocks,
+                attachments: arguments.attachments,
+            };
+            return Box::pin(future::ready(self.update_message(update)));
+        }
+        let result = self.post(arguments).map(|response| {
+            upserted.insert(key, (response.channel, response.ts.clone()));
+            response.ts
+        });
+        Box::pin(future::ready(result))
+    }
+
+    /// Records a message starting a thread.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_thread(&self, arguments: ChatPostMessageArguments) -> Result<ThreadHandle<'_, Self>, SlackApiError> {
+        futures::executor::block_on(self.post_thread_async(arguments))
+    }
+
+    /// Records a message starting a thread asynchronously.
+    fn post_thread_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<ThreadHandle<'_, Self>, SlackApiError>> + Send + '_>> {
+        start_thread(self, arguments)
+    }
+
+    /// Records a message scheduled at a local time in a time zone.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32"), feature = "chrono-tz"))]
+    fn schedule_message_at(&self, arguments: ChatPostMessageArguments, local_time: NaiveDateTime, tz: Tz) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.schedule_message_at_async(arguments, local_time, tz))
+    }
+
+    /// Records a message scheduled at a local time in a time zone asynchronously.
+    #[cfg(feature = "chrono-tz")]
+    fn schedule_message_at_async(&self, arguments: ChatPostMessageArguments, local_time: NaiveDateTime, tz: Tz) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        match local_post_at(local_time, tz) {
+            Ok(post_at) => self.schedule_message_async(arguments, post_at),
+            Err(e) => Box::pin(future::ready(Err(e))),
+        }
+    }
+
+    /// Records the deletion of the message of a permalink.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn delete_by_permalink(&self, permalink: impl Into<String>) -> Result<(), SlackApiError> {
+        futures::executor::block_on(self.delete_by_permalink_async(permalink))
+    }
+
+    /// Records the deletion of the message of a permalink asynchronously.
+    fn delete_by_permalink_async(&self, permalink: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        match parse_permalink(&permalink.into()) {
+            Ok((channel, ts)) => self.delete_async(channel, ts),
+            Err(e) => Box::pin(future::ready(Err(e))),
+        }
+    }
+
+    /// Checks the previews of the links of a message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn unfurl(&self, channel: impl Into<String>, ts: impl Into<String>, unfurls: Value) -> Result<(), SlackApiError> {
+        futures::executor::block_on(self.unfurl_async(channel, ts, unfurls))
+    }
+
+    /// Checks the previews of the links of a message asynchronously.
+    fn unfurl_async(&self, _channel: impl Into<String>, _ts: impl Into<String>, unfurls: Value) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        Box::pin(future::ready(validate_unfurls(&unfurls)))
+    }
+
+    /// Records a code snippet.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_snippet(&self, channel: impl Into<String>, title: impl Into<String>, content: impl Into<String>, language: Option<String>) -> Result<String, SlackApiError> {
+        futures::executor::block_on(self.post_snippet_async(channel, title, content, language))
+    }
+
+    /// Records a code snippet asynchronously.
+    fn post_snippet_async(&self, channel: impl Into<String>, title: impl Into<String>, content: impl Into<String>, language: Option<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let text = snippet_text(&title.into(), &content.into(), language.as_deref());
+        self.post_message_text_async(channel, text)
+    }
+
+    /// Sends a message to a channel and returns its ts as a Ts.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_ts(&self, arguments: ChatPostMessageArguments) -> Result<Ts, SlackApiError> {
+        futures::executor::block_on(self.post_message_ts_async(arguments))
+    }
+
+    /// Sends a message to a channel and returns its ts as a Ts asynchronously.
+    fn post_message_ts_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<Ts, SlackApiError>> + Send + '_>> {
+        let post = self.post_message_async(arguments);
+        Box::pin(async move { post.await?.parse() })
+    }
+
+    /// Sends a message with text only and returns its ts as a Ts.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn post_message_text_ts(&self, channel: impl Into<String>, text: impl Into<String>) -> Result<Ts, SlackApiError> {
+        futures::executor::block_on(self.post_message_text_ts_async(channel, text))
+    }
+
+    /// Sends a message with text only and returns its ts as a Ts asynchronously.
+    fn post_message_text_ts_async(&self, channel: impl Into<String>, text: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Ts, SlackApiError>> + Send + '_>> {
+        let post = self.post_message_text_async(channel, text);
+        Box::pin(async move { post.await?.parse() })
+    }
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod mock_tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn mock_records_posted_messages() {
+        let mock = Arc::new(MockChat::new());
+
+        // Code under test only sees the trait
+        let chat: Arc<dyn Chat> = mock.clone();
+        let arguments = ChatPostMessageArguments::builder("C0000000000").text("Build passed").build();
+        assert_eq!(chat.post_message(arguments.clone()).unwrap(), "1700000000.000001");
+        assert_eq!(mock.reply_in_thread("C0000000000", "1700000000.000001", "Details", false).unwrap(), "1700000000.000002");
+
+        assert_eq!(mock.posted_messages()[0], arguments);
+        assert_eq!(mock.posted_messages()[1].thread_ts.as_deref(), Some("1700000000.000001"));
+
+        // Invalid messages are rejected like the Slack client does
+        let post = chat.post_message(ChatPostMessageArguments::builder("C0000000000").build());
+        assert!(matches!(post, Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error");
+        assert_eq!(mock.posted_messages().len(), 2);
+    }
+
+    #[test]
+    fn mock_upsert_message() {
+        let mock = MockChat::new();
+
+        // The first call posts and the second updates the same message
+        let ts = mock.upsert_message("status", ChatPostMessageArguments::builder("C0000000000").text("Deploy: running").build()).unwrap();
+        let updated = mock.upsert_message("status", ChatPostMessageArguments::builder("C0000000000").text("Deploy: done").build()).unwrap();
+        assert_eq!(updated, ts);
+        assert_eq!(mock.posted_messages().len(), 1);
+        assert_eq!(mock.updated_messages()[0].text.as_deref(), Some("Deploy: done"));
+    }
+}
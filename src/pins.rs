@@ -0,0 +1,130 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::errors::SlackApiError;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use crate::slack_client::block_on_current_thread;
+use crate::SlackClient;
+
+/// Pins trait for the Slack API client.
+pub trait Pins: Send + Sync {
+    /// Pins a message to a channel.
+    ///
+    /// Returns `SlackApiError::AlreadyPinned` if the message is already pinned.
+    ///
+    /// <https://api.slack.com/methods/pins.add>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn add(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.add_async(channel, timestamp))
+    }
+
+    /// Pins a message to a channel asynchronously.
+    ///
+    /// <https://api.slack.com/methods/pins.add>
+    fn add_async(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Unpins a message from a channel.
+    ///
+    /// <https://api.slack.com/methods/pins.remove>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn remove(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.remove_async(channel, timestamp))
+    }
+
+    /// Unpins a message from a channel asynchronously.
+    ///
+    /// <https://api.slack.com/methods/pins.remove>
+    fn remove_async(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
+}
+
+/// Implement the Pins trait for SlackClient.
+impl Pins for SlackClient {
+    /// Pins a message to a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn add(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Result<(), SlackApiError> {
+        self.block_on(self.add_async(channel, timestamp))
+    }
+
+    /// Pins a message to a channel asynchronously.
+    fn add_async(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let timestamp: String = timestamp.into();
+
+        Box::pin(async move {
+            self.post_form("pins.add", &[("channel", &channel), ("timestamp", &timestamp)]).await?;
+            Ok(())
+        })
+    }
+
+    /// Unpins a message from a channel.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn remove(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Result<(), SlackApiError> {
+        self.block_on(self.remove_async(channel, timestamp))
+    }
+
+    /// Unpins a message from a channel asynchronously.
+    fn remove_async(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let timestamp: String = timestamp.into();
+
+        Box::pin(async move {
+            self.post_form("pins.remove", &[("channel", &channel), ("timestamp", &timestamp)]).await?;
+            Ok(())
+        })
+    }
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod pins_tests {
+    use std::env;
+
+    use serde_json::json;
+    use tokio::runtime::Runtime;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::Chat;
+
+    use super::*;
+
+    #[test]
+    fn pins_add_and_remove() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Post a message to the channel
+        let post = client.post_message_text(channel_id.to_string(), "Hello, Slack from Rust!".to_string());
+        assert!(post.is_ok(), "Failed to post message");
+        let message_id = post.unwrap();
+
+        // Pin and unpin the message
+        let add = client.add(channel_id.to_string(), message_id.to_string());
+        assert!(add.is_ok(), "Failed to pin message");
+        let remove = client.remove(channel_id.to_string(), message_id.to_string());
+        assert!(remove.is_ok(), "Failed to unpin message");
+
+        // Delete the message from the channel
+        let delete = client.delete(channel_id, message_id);
+        assert!(delete.is_ok(), "Failed to delete message");
+    }
+
+    #[test]
+    fn pins_add_already_pinned() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/pins.add"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "already_pinned" })))
+                .mount(&server)
+        );
+
+        // Pinning a pinned message returns a typed error
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let add = client.add("C0000000000".to_string(), "1700000000.000100".to_string());
+        assert!(matches!(add, Err(SlackApiError::AlreadyPinned)), "Expected an already pinned error");
+    }
+}
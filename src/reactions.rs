@@ -0,0 +1,221 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::SlackApiError;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use crate::slack_client::block_on_current_thread;
+use crate::slack_client::decode_response;
+use crate::SlackClient;
+
+/// A reaction on a message.
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Reaction {
+    /// Emoji name of the reaction, without colons.
+    pub name: String,
+    /// Number of users who reacted.
+    #[serde(default)]
+    pub count: u32,
+    /// IDs of the users who reacted.
+    #[serde(default)]
+    pub users: Vec<String>,
+}
+
+/// Reactions trait for the Slack API client.
+pub trait Reactions: Send + Sync {
+    /// Adds a reaction to a message. `name` is the emoji name without colons.
+    ///
+    /// Returns `SlackApiError::AlreadyReacted` if the reaction is already present.
+    ///
+    /// <https://api.slack.com/methods/reactions.add>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn add(&self, channel: impl Into<String>, timestamp: impl Into<String>, name: impl Into<String>) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.add_async(channel, timestamp, name))
+    }
+
+    /// Adds a reaction to a message asynchronously.
+    ///
+    /// <https://api.slack.com/methods/reactions.add>
+    fn add_async(&self, channel: impl Into<String>, timestamp: impl Into<String>, name: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Removes a reaction from a message. `name` is the emoji name without colons.
+    ///
+    /// <https://api.slack.com/methods/reactions.remove>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn remove(&self, channel: impl Into<String>, timestamp: impl Into<String>, name: impl Into<String>) -> Result<(), SlackApiError> where Self: Sized {
+        block_on_current_thread(self.remove_async(channel, timestamp, name))
+    }
+
+    /// Removes a reaction from a message asynchronously.
+    ///
+    /// <https://api.slack.com/methods/reactions.remove>
+    fn remove_async(&self, channel: impl Into<String>, timestamp: impl Into<String>, name: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Gets the reactions on a message, with the complete list of users for each.
+    ///
+    /// Returns `SlackApiError::MessageNotFound` if the message does not exist.
+    ///
+    /// <https://api.slack.com/methods/reactions.get>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn get(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Result<Vec<Reaction>, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.get_async(channel, timestamp))
+    }
+
+    /// Gets the reactions on a message asynchronously.
+    ///
+    /// <https://api.slack.com/methods/reactions.get>
+    fn get_async(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Vec<Reaction>, SlackApiError>> + Send + '_>> where Self: Sized;
+}
+
+/// Implement the Reactions trait for SlackClient.
+impl Reactions for SlackClient {
+    /// Adds a reaction to a message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn add(&self, channel: impl Into<String>, timestamp: impl Into<String>, name: impl Into<String>) -> Result<(), SlackApiError> {
+        self.block_on(self.add_async(channel, timestamp, name))
+    }
+
+    /// Adds a reaction to a message asynchronously.
+    fn add_async(&self, channel: impl Into<String>, timestamp: impl Into<String>, name: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let timestamp: String = timestamp.into();
+        let name: String = name.into();
+
+        Box::pin(async move {
+            let name = name.trim_matches(':');
+            self.post_form("reactions.add", &[("channel", channel.as_str()), ("timestamp", timestamp.as_str()), ("name", name)]).await?;
+            Ok(())
+        })
+    }
+
+    /// Removes a reaction from a message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn remove(&self, channel: impl Into<String>, timestamp: impl Into<String>, name: impl Into<String>) -> Result<(), SlackApiError> {
+        self.block_on(self.remove_async(channel, timestamp, name))
+    }
+
+    /// Removes a reaction from a message asynchronously.
+    fn remove_async(&self, channel: impl Into<String>, timestamp: impl Into<String>, name: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let timestamp: String = timestamp.into();
+        let name: String = name.into();
+
+        Box::pin(async move {
+            let name = name.trim_matches(':');
+            self.post_form("reactions.remove", &[("channel", channel.as_str()), ("timestamp", timestamp.as_str()), ("name", name)]).await?;
+            Ok(())
+        })
+    }
+
+    /// Gets the reactions on a message.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn get(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Result<Vec<Reaction>, SlackApiError> {
+        self.block_on(self.get_async(channel, timestamp))
+    }
+
+    /// Gets the reactions on a message asynchronously.
+    fn get_async(&self, channel: impl Into<String>, timestamp: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<Vec<Reaction>, SlackApiError>> + Send + '_>> {
+        let channel: String = channel.into();
+        let timestamp: String = timestamp.into();
+
+        Box::pin(async move {
+            // Without full, the user lists are truncated
+            let body = self.get_query("reactions.get", &[("channel", channel.as_str()), ("timestamp", timestamp.as_str()), ("full", "true")]).await?;
+
+            // Messages without reactions have no reactions field
+            match body["message"].get("reactions") {
+                Some(reactions) => decode_response(reactions.clone()),
+                None => Ok(Vec::new()),
+            }
+        })
+    }
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod reactions_tests {
+    use std::env;
+
+    use serde_json::json;
+    use tokio::runtime::Runtime;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::Chat;
+
+    use super::*;
+
+    #[test]
+    fn reactions_add_and_remove() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let channel_id = env::var("SLACK_CHANNEL_ID").expect("Expected a channel id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Post a message to the channel
+        let post = client.post_message_text(channel_id.to_string(), "Hello, Slack from Rust!".to_string());
+        assert!(post.is_ok(), "Failed to post message");
+        let message_id = post.unwrap();
+
+        // Add a reaction, then add it again
+        let add = client.add(channel_id.to_string(), message_id.to_string(), "white_check_mark".to_string());
+        assert!(add.is_ok(), "Failed to add reaction");
+        let add = client.add(channel_id.to_string(), message_id.to_string(), "white_check_mark".to_string());
+        assert!(matches!(add, Err(SlackApiError::AlreadyReacted)), "Expected an already reacted error");
+
+        // The reaction is listed with the bot as one of its users
+        let get = client.get(channel_id.to_string(), message_id.to_string());
+        assert!(get.is_ok(), "Failed to get reactions");
+        assert!(get.unwrap().iter().any(|reaction| reaction.name == "white_check_mark"), "Reaction not found");
+
+        // Remove the reaction
+        let remove = client.remove(channel_id.to_string(), message_id.to_string(), "white_check_mark".to_string());
+        assert!(remove.is_ok(), "Failed to remove reaction");
+
+        // Delete the message from the channel
+        let delete = client.delete(channel_id, message_id);
+        assert!(delete.is_ok(), "Failed to delete message");
+    }
+
+    #[test]
+    fn reactions_get_from_mock() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/reactions.get"))
+                .and(query_param("full", "true"))
+                .and(query_param("timestamp", "1700000000.000100"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "type": "message",
+                    "channel": "C0000000000",
+                    "message": {
+                        "ts": "1700000000.000100",
+                        "reactions": [{ "name": "thumbsup", "count": 2, "users": ["U0000000001", "U0000000002"] }],
+                    },
+                })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/reactions.get"))
+                .and(query_param("timestamp", "1700000000.000200"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "message_not_found" })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let reactions = client.get("C0000000000".to_string(), "1700000000.000100".to_string()).unwrap();
+        assert_eq!(reactions, vec![Reaction {
+            name: "thumbsup".to_string(),
+            count: 2,
+            users: vec!["U0000000001".to_string(), "U0000000002".to_string()],
+        }]);
+
+        // A deleted message is reported with a typed error
+        let reactions = client.get("C0000000000".to_string(), "1700000000.000200".to_string());
+        assert_eq!(reactions.unwrap_err(), SlackApiError::MessageNotFound);
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Slack API methods that are not safe to repeat, because a retry could post or create twice.
+const NON_IDEMPOTENT_METHODS: &[&str] = &[
+    "chat.postMessage",
+    "chat.postEphemeral",
+    "chat.scheduleMessage",
+    "files.completeUploadExternal",
+];
+
+/// Policy for retrying requests that failed with a 5xx server error.
+///
+/// Delays grow exponentially from `base_delay` up to `max_delay`, with full jitter.
+/// Rate limited (429) requests are handled separately through `max_retries` on the client.
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after a server error.
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Upper bound for the delay between retries.
+    pub max_delay: Duration,
+    /// Also retry methods that are not idempotent, such as chat.postMessage, which may then post twice.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether a request to `method` may be retried under this policy.
+    pub(crate) fn allows(&self, method: &str) -> bool {
+        self.retry_non_idempotent || !NON_IDEMPOTENT_METHODS.contains(&method)
+    }
+
+    /// Delay before the retry following `attempt` failed attempts, with full jitter.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let ceiling = self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let millis = ceiling.as_millis() as u64;
+        if millis == 0 {
+            return Duration::ZERO;
+        }
+
+        Duration::from_millis(random() % (millis + 1))
+    }
+}
+
+/// Budget of retries shared by all requests of a client and its clones, to avoid retry storms during an incident.
+///
+/// Each retry, of a rate limited request or a server error, takes a token, and each successful request puts back
+/// a fraction of one. Once the tokens run out, requests are no longer retried even if `max_retries` or the retry
+/// policy allow it, until enough requests succeed again.
+#[derive(Debug)]
+pub struct RetryBudget {
+    max_tokens: f64,
+    refill_per_success: f64,
+    tokens: Mutex<f64>,
+}
+
+impl Default for RetryBudget {
+    fn default() -> Self {
+        RetryBudget::new(10, 0.1)
+    }
+}
+
+impl RetryBudget {
+    /// Create a full budget of `max_tokens` retries, refilled by `refill_per_success` retries for each successful request.
+    pub fn new(max_tokens: u32, refill_per_success: f64) -> Self {
+        RetryBudget {
+            max_tokens: max_tokens as f64,
+            refill_per_success: refill_per_success.max(0.0),
+            tokens: Mutex::new(max_tokens as f64),
+        }
+    }
+
+    /// Number of retries currently left in the budget, e.g. to report as a metric.
+    pub fn available(&self) -> f64 {
+        *self.tokens.lock().unwrap()
+    }
+
+    /// Take a token for a retry, returning false if the budget is exhausted.
+    pub(crate) fn withdraw(&self) -> bool {
+        let mut tokens = self.tokens.lock().unwrap();
+        if *tokens < 1.0 {
+            return false;
+        }
+
+        *tokens -= 1.0;
+        true
+    }
+
+    /// Put back part of a token after a successful request.
+    pub(crate) fn deposit(&self) {
+        let mut tokens = self.tokens.lock().unwrap();
+        *tokens = (*tokens + self.refill_per_success).min(self.max_tokens);
+    }
+}
+
+/// A random number, good enough to spread out retries without pulling in a dependency.
+fn random() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+
+#[cfg(test)]
+mod retry_tests {
+    use super::*;
+
+    #[test]
+    fn retry_backoff_is_capped() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(250),
+            ..Default::default()
+        };
+
+        for attempt in 0..10 {
+            assert!(policy.backoff(attempt) <= Duration::from_millis(250));
+        }
+        assert!(policy.backoff(0) <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn retry_skips_non_idempotent_methods() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows("chat.delete"));
+        assert!(!policy.allows("chat.postMessage"));
+
+        let policy = RetryPolicy { retry_non_idempotent: true, ..Default::default() };
+        assert!(policy.allows("chat.postMessage"));
+    }
+
+    #[test]
+    fn retry_budget_refills_on_success() {
+        let budget = RetryBudget::new(2, 0.5);
+        assert!(budget.withdraw());
+        assert!(budget.withdraw());
+        assert!(!budget.withdraw(), "Expected the budget to be exhausted");
+
+        // Two successes earn back a retry, and the budget never exceeds its maximum
+        budget.deposit();
+        budget.deposit();
+        assert_eq!(budget.available(), 1.0);
+        for _ in 0..10 {
+            budget.deposit();
+        }
+        assert_eq!(budget.available(), 2.0);
+    }
+}
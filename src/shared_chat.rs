@@ -0,0 +1,280 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+#[cfg(feature = "chrono-tz")]
+use chrono::NaiveDateTime;
+#[cfg(feature = "chrono-tz")]
+use chrono_tz::Tz;
+
+use crate::chat::{Chat, ChatPostMessageArguments, ChatPostMessageResponse, ChatUpdateArguments, ScheduledMessage};
+use crate::errors::SlackApiError;
+use crate::ts::Ts;
+
+/// Object-safe version of the async methods of the Chat trait, for handlers taking an `Arc<dyn DynChat>`.
+///
+/// Unlike `Chat`, every method is available on the trait object, taking `String` arguments, and the returned
+/// futures are `'static`, so they can be spawned without cloning the client first. Implemented by `SharedChat`
+/// for any Chat implementation, e.g. `SlackClient` in production and `MockChat` in tests.
+pub trait DynChat: Send + Sync {
+    /// Deletes a message from a channel, see `Chat::delete_async`.
+    fn delete_async(&self, channel: String, ts: String) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + 'static>>;
+
+    /// Sends a message to a channel, see `Chat::post_message_async`.
+    fn post_message_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Sends a message to a channel and returns the full response, see `Chat::post_message_full_async`.
+    fn post_message_full_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<ChatPostMessageResponse, SlackApiError>> + Send + 'static>>;
+
+    /// Sends a message to a channel with text only, see `Chat::post_message_text_async`.
+    fn post_message_text_async(&self, channel: String, text: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Sends a message with text only to the default channel, see `Chat::post_message_text_default_async`.
+    fn post_message_text_default_async(&self, text: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Replies to a thread with text only, see `Chat::reply_in_thread_async`.
+    fn reply_in_thread_async(&self, channel: String, thread_ts: String, text: String, broadcast: bool) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Updates a message, see `Chat::update_async`.
+    fn update_async(&self, arguments: ChatUpdateArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Schedules a message, see `Chat::schedule_message_async`.
+    fn schedule_message_async(&self, arguments: ChatPostMessageArguments, post_at: i64) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Deletes a scheduled message, see `Chat::delete_scheduled_message_async`.
+    fn delete_scheduled_message_async(&self, channel: String, scheduled_message_id: String) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + 'static>>;
+
+    /// Returns the permalink of a message, see `Chat::get_permalink_async`.
+    fn get_permalink_async(&self, channel: String, message_ts: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Sends an ephemeral message to a user in a channel, see `Chat::post_ephemeral_async`.
+    fn post_ephemeral_async(&self, arguments: ChatPostMessageArguments, user: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Lists the pending scheduled messages, see `Chat::list_scheduled_messages_async`.
+    fn list_scheduled_messages_async(&self, channel: Option<String>) -> Pin<Box<dyn Future<Output=Result<Vec<ScheduledMessage>, SlackApiError>> + Send + 'static>>;
+
+    /// Sends the same message to several channels, see `Chat::post_message_multi_async`.
+    #[allow(clippy::type_complexity)]
+    fn post_message_multi_async(&self, channels: Vec<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Vec<Result<String, SlackApiError>>> + Send + 'static>>;
+
+    /// Deletes several messages of a channel, see `Chat::delete_many_async`.
+    #[allow(clippy::type_complexity)]
+    fn delete_many_async(&self, channel: String, timestamps: Vec<String>) -> Pin<Box<dyn Future<Output=Vec<Result<(), SlackApiError>>> + Send + 'static>>;
+
+    /// Sends a /me message, see `Chat::post_me_message_async`.
+    fn post_me_message_async(&self, channel: String, text: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Sends a message once per key, see `Chat::post_message_once_async`.
+    fn post_message_once_async(&self, key: String, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Sends a message until `cancel` is cancelled, see `Chat::post_message_cancellable_async`.
+    fn post_message_cancellable_async(&self, arguments: ChatPostMessageArguments, cancel: CancellationToken) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Sends or updates the message of a key, see `Chat::upsert_message_async`.
+    fn upsert_message_async(&self, key: String, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Schedules a message at a local time in a time zone, see `Chat::schedule_message_at_async`.
+    #[cfg(feature = "chrono-tz")]
+    fn schedule_message_at_async(&self, arguments: ChatPostMessageArguments, local_time: NaiveDateTime, tz: Tz) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Deletes the message of a permalink, see `Chat::delete_by_permalink_async`.
+    fn delete_by_permalink_async(&self, permalink: String) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + 'static>>;
+
+    /// Attaches previews to the URLs of a message, see `Chat::unfurl_async`.
+    fn unfurl_async(&self, channel: String, ts: String, unfurls: Value) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + 'static>>;
+
+    /// Sends content as a code block, see `Chat::post_snippet_async`.
+    fn post_snippet_async(&self, channel: String, title: String, content: String, language: Option<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>>;
+
+    /// Sends a message to a channel and returns its ts as a `Ts`, see `Chat::post_message_ts_async`.
+    fn post_message_ts_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<Ts, SlackApiError>> + Send + 'static>>;
+
+    /// Sends a message to a channel with text only and returns its ts as a `Ts`, see `Chat::post_message_text_ts_async`.
+    fn post_message_text_ts_async(&self, channel: String, text: String) -> Pin<Box<dyn Future<Output=Result<Ts, SlackApiError>> + Send + 'static>>;
+}
+
+/// A Chat implementation shared behind an `Arc`, usable as an `Arc<dyn DynChat>`.
+///
+/// Each method moves a clone of the `Arc` into the returned future, which is why the futures are `'static`.
+/// `post_thread` is left out, because its thread handle borrows the client.
+pub struct SharedChat<C> {
+    chat: Arc<C>,
+}
+
+impl<C: Chat + 'static> SharedChat<C> {
+    /// Share `chat`, e.g. a `SlackClient` or a `MockChat`.
+    pub fn new(chat: C) -> Self {
+        SharedChat { chat: Arc::new(chat) }
+    }
+
+    /// The shared Chat implementation, e.g. to read what a `MockChat` recorded.
+    pub fn inner(&self) -> &Arc<C> {
+        &self.chat
+    }
+}
+
+impl<C: Chat + 'static> From<Arc<C>> for SharedChat<C> {
+    fn from(chat: Arc<C>) -> Self {
+        SharedChat { chat }
+    }
+}
+
+/// Cloning shares the same Chat implementation.
+impl<C> Clone for SharedChat<C> {
+    fn clone(&self) -> Self {
+        SharedChat { chat: self.chat.clone() }
+    }
+}
+
+impl<C: Chat + 'static> DynChat for SharedChat<C> {
+    fn delete_async(&self, channel: String, ts: String) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.delete_async(channel, ts).await })
+    }
+
+    fn post_message_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_message_async(arguments).await })
+    }
+
+    fn post_message_full_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<ChatPostMessageResponse, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_message_full_async(arguments).await })
+    }
+
+    fn post_message_text_async(&self, channel: String, text: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_message_text_async(channel, text).await })
+    }
+
+    fn post_message_text_default_async(&self, text: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_message_text_default_async(text).await })
+    }
+
+    fn reply_in_thread_async(&self, channel: String, thread_ts: String, text: String, broadcast: bool) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.reply_in_thread_async(channel, thread_ts, text, broadcast).await })
+    }
+
+    fn update_async(&self, arguments: ChatUpdateArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.update_async(arguments).await })
+    }
+
+    fn schedule_message_async(&self, arguments: ChatPostMessageArguments, post_at: i64) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.schedule_message_async(arguments, post_at).await })
+    }
+
+    fn delete_scheduled_message_async(&self, channel: String, scheduled_message_id: String) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.delete_scheduled_message_async(channel, scheduled_message_id).await })
+    }
+
+    fn get_permalink_async(&self, channel: String, message_ts: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.get_permalink_async(channel, message_ts).await })
+    }
+
+    fn post_ephemeral_async(&self, arguments: ChatPostMessageArguments, user: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_ephemeral_async(arguments, user).await })
+    }
+
+    fn list_scheduled_messages_async(&self, channel: Option<String>) -> Pin<Box<dyn Future<Output=Result<Vec<ScheduledMessage>, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.list_scheduled_messages_async(channel).await })
+    }
+
+    fn post_message_multi_async(&self, channels: Vec<String>, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Vec<Result<String, SlackApiError>>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_message_multi_async(channels, arguments).await })
+    }
+
+    fn delete_many_async(&self, channel: String, timestamps: Vec<String>) -> Pin<Box<dyn Future<Output=Vec<Result<(), SlackApiError>>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.delete_many_async(channel, timestamps).await })
+    }
+
+    fn post_me_message_async(&self, channel: String, text: String) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_me_message_async(channel, text).await })
+    }
+
+    fn post_message_once_async(&self, key: String, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_message_once_async(key, arguments).await })
+    }
+
+    fn post_message_cancellable_async(&self, arguments: ChatPostMessageArguments, cancel: CancellationToken) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_message_cancellable_async(arguments, cancel).await })
+    }
+
+    fn upsert_message_async(&self, key: String, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.upsert_message_async(key, arguments).await })
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    fn schedule_message_at_async(&self, arguments: ChatPostMessageArguments, local_time: NaiveDateTime, tz: Tz) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.schedule_message_at_async(arguments, local_time, tz).await })
+    }
+
+    fn delete_by_permalink_async(&self, permalink: String) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.delete_by_permalink_async(permalink).await })
+    }
+
+    fn unfurl_async(&self, channel: String, ts: String, unfurls: Value) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.unfurl_async(channel, ts, unfurls).await })
+    }
+
+    fn post_snippet_async(&self, channel: String, title: String, content: String, language: Option<String>) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_snippet_async(channel, title, content, language).await })
+    }
+
+    fn post_message_ts_async(&self, arguments: ChatPostMessageArguments) -> Pin<Box<dyn Future<Output=Result<Ts, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_message_ts_async(arguments).await })
+    }
+
+    fn post_message_text_ts_async(&self, channel: String, text: String) -> Pin<Box<dyn Future<Output=Result<Ts, SlackApiError>> + Send + 'static>> {
+        let chat = self.chat.clone();
+        Box::pin(async move { chat.post_message_text_ts_async(channel, text).await })
+    }
+}
+
+
+#[cfg(all(test, feature = "mock"))]
+mod shared_chat_tests {
+    use std::sync::Arc;
+
+    use tokio::runtime::Runtime;
+
+    use crate::MockChat;
+
+    use super::*;
+
+    #[test]
+    fn shared_chat_spawns_static_futures() {
+        let runtime = Runtime::new().unwrap();
+        let mock = Arc::new(MockChat::new());
+        let chat: Arc<dyn DynChat> = Arc::new(SharedChat::from(mock.clone()));
+
+        // The futures own a clone of the client, so they can be spawned without cloning the trait object
+        let post = runtime.block_on(runtime.spawn(chat.post_message_text_async("C0000000000".to_string(), "Hello".to_string()))).unwrap();
+        let ts = post.unwrap();
+        let delete = runtime.block_on(runtime.spawn(chat.delete_async("C0000000000".to_string(), ts.clone()))).unwrap();
+        assert_eq!(delete, Ok(()));
+
+        assert_eq!(mock.posted_messages()[0].text.as_deref(), Some("Hello"));
+        assert_eq!(mock.deleted_messages(), vec![("C0000000000".to_string(), ts)]);
+    }
+}
@@ -1,28 +1,1010 @@
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::env;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use reqwest::Client;
-use tokio::runtime::Runtime;
+use reqwest::header::HeaderMap;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::redirect::Policy;
+use reqwest::{Client, ClientBuilder, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use tokio::sync::Semaphore;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use tokio::runtime::{Handle, Runtime};
+
+use crate::errors::SlackApiError;
+use crate::retry::{RetryBudget, RetryPolicy};
+
+/// Base URL of the Slack Web API.
+const SLACK_API_URL: &str = "https://slack.com/api";
+
+/// Prefixes of the token types accepted by `SlackClient::try_new`: bot, user, and app-level tokens.
+const TOKEN_PREFIXES: [&str; 3] = ["xoxb-", "xoxp-", "xapp-"];
+
+/// Maximum number of characters of an unexpected response body kept in the error.
+const SNIPPET_LEN: usize = 200;
+
+/// Timestamp returned for messages that were not posted because the client is in dry-run mode.
+pub(crate) const DRY_RUN_TS: &str = "0000000000.000000";
+
+/// Slack API methods that are not sent in dry-run mode, because they post, change, or delete messages.
+const DRY_RUN_METHODS: &[&str] = &[
+    "chat.postMessage",
+    "chat.postEphemeral",
+    "chat.meMessage",
+    "chat.scheduleMessage",
+    "chat.deleteScheduledMessage",
+    "chat.update",
+    "chat.delete",
+    "chat.unfurl",
+    "reactions.add",
+    "reactions.remove",
+    "pins.add",
+    "pins.remove",
+];
+
+/// Whether requests can wait before a retry or between polls. wasm32 has no Tokio timer driver, so they never wait there.
+pub(crate) const CAN_WAIT: bool = cfg!(not(target_arch = "wasm32"));
+
+/// Response headers kept for observability, e.g. to quote the request ID when contacting Slack support.
+const CAPTURED_HEADERS: [&str; 4] = ["x-slack-req-id", "retry-after", "x-oauth-scopes", "x-accepted-oauth-scopes"];
 
 /// Slack API client.
+///
+/// Cloning is cheap: the token, HTTP client, and runtime are shared between clones.
+#[derive(Clone)]
 pub struct SlackClient {
     /// Slack API token.
-    pub token: String,
+    pub token: Arc<str>,
     /// Reqwest client.
     pub client: Client,
-    /// Tokio runtime.
-    pub runtime: Arc<Runtime>,
+    /// Tokio runtime used by the blocking methods. `None` for async-only clients.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub runtime: Option<Arc<Runtime>>,
+    /// Maximum number of retries for rate limited requests.
+    pub max_retries: u32,
+    /// Base URL of the Slack Web API, without a trailing slash.
+    pub base_url: String,
+    /// Policy for retrying requests that failed with a server error. `None` disables these retries.
+    pub retry_policy: Option<RetryPolicy>,
+    /// Budget limiting the retries of all requests, shared between clones. `None` for no limit beyond the retry counts.
+    pub retry_budget: Option<Arc<RetryBudget>>,
+    /// Channel used by the methods that post without an explicit channel.
+    pub default_channel: Option<String>,
+    /// Record the requests that would change messages instead of sending them, returning a synthetic ts.
+    pub dry_run: bool,
+    /// Headers added to every request. The authorization and content type of each call take precedence.
+    pub default_headers: HeaderMap,
+    /// Limits the number of requests in flight at once, shared between clones. `None` for no limit.
+    pub limiter: Option<Arc<Semaphore>>,
+    /// Timestamps of the messages posted with `post_message_once`, by dedup key. Shared between clones.
+    pub(crate) posted_once: Arc<Mutex<HashMap<String, String>>>,
+    /// Channel and timestamp of the messages posted with `upsert_message`, by key. Shared between clones.
+    pub(crate) upserted: Arc<Mutex<HashMap<String, (String, String)>>>,
+    /// Method and JSON payload of the requests not sent in dry-run mode, oldest first. Shared between clones.
+    pub(crate) dry_run_requests: Arc<Mutex<Vec<(String, Value)>>>,
 }
 
 /// Implementation of the Slack API client.
 impl SlackClient {
     /// Create a new Slack API client.
     pub fn new(token: String) -> Self {
-        let runtime = Arc::new(Runtime::new().unwrap());
+        SlackClient::with_client(token, default_client())
+    }
+
+    /// Create a new Slack API client, checking that `token` looks like a bot, user, or app-level token.
+    ///
+    /// Returns `SlackApiError::InvalidArgument` for an empty token or one without a `xoxb-`, `xoxp-`, or `xapp-` prefix.
+    pub fn try_new(token: String) -> Result<Self, SlackApiError> {
+        if token.is_empty() {
+            return Err(SlackApiError::InvalidArgument("token is required".into()));
+        }
+        if !TOKEN_PREFIXES.iter().any(|prefix| token.len() > prefix.len() && token.starts_with(prefix)) {
+            return Err(SlackApiError::InvalidArgument("token must start with xoxb-, xoxp-, or xapp-".into()));
+        }
+
+        Ok(SlackClient::new(token))
+    }
+
+    /// Create a new Slack API client with the token from the `SLACK_TOKEN` environment variable.
+    ///
+    /// The HTTP client respects the system proxy environment variables, e.g. `HTTPS_PROXY`.
+    pub fn from_env() -> Result<Self, SlackApiError> {
+        let token = env::var("SLACK_TOKEN").map_err(|_| SlackApiError::InvalidArgument("SLACK_TOKEN is not set".into()))?;
+        let client = client_builder()
+            .build()
+            .map_err(|e| SlackApiError::ClientBuildFailed(e.to_string()))?;
+
+        Ok(SlackClient::with_client(token, client))
+    }
+
+    /// Create a new async-only Slack API client that does not create a Tokio runtime.
+    ///
+    /// Only the `_async` methods can be used; the blocking methods return `SlackApiError::RuntimeError`.
+    pub fn new_async(token: String) -> Self {
+        SlackClient::from_parts(token, default_client())
+    }
+
+    /// Create a new Slack API client whose requests time out after `timeout`.
+    ///
+    /// Not available on WASM targets, whose HTTP client has no client-wide timeout.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_timeout(token: String, timeout: Duration) -> Result<Self, SlackApiError> {
+        let client = client_builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| SlackApiError::ClientBuildFailed(e.to_string()))?;
+
+        Ok(SlackClient::with_client(token, client))
+    }
+
+    /// Create a new Slack API client that retries rate limited requests up to `max_retries` times.
+    ///
+    /// Requests are never retried on wasm32, which has no timer to wait with; the `RateLimited` error is returned instead.
+    pub fn with_retry(token: String, max_retries: u32) -> Self {
+        SlackClient {
+            max_retries,
+            ..SlackClient::new(token)
+        }
+    }
+
+    /// Create a new Slack API client that retries server errors according to `retry_policy`.
+    ///
+    /// Requests are never retried on wasm32, which has no timer to wait with.
+    pub fn with_retry_policy(token: String, retry_policy: RetryPolicy) -> Self {
+        SlackClient {
+            retry_policy: Some(retry_policy),
+            ..SlackClient::new(token)
+        }
+    }
+
+    /// Create a new Slack API client whose retries, of rate limited requests and server errors, draw from `retry_budget`.
+    ///
+    /// The budget is shared by all clones of the client; read `retry_budget.available()` to report it as a metric.
+    pub fn with_retry_budget(token: String, retry_budget: RetryBudget) -> Self {
+        SlackClient {
+            retry_budget: Some(Arc::new(retry_budget)),
+            ..SlackClient::new(token)
+        }
+    }
+
+    /// Create a new Slack API client that sends requests to `base_url` instead of `https://slack.com/api`.
+    ///
+    /// This is mostly useful to point the client at a mock server in tests.
+    pub fn with_base_url(token: String, base_url: String) -> Self {
+        SlackClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            ..SlackClient::new(token)
+        }
+    }
+
+    /// Create a new Slack API client that posts to `channel` when no channel is given.
+    pub fn with_default_channel(token: String, channel: String) -> Self {
+        SlackClient {
+            default_channel: Some(channel),
+            ..SlackClient::new(token)
+        }
+    }
+
+    /// Create a new Slack API client that records the requests that would post, change, or delete messages instead of sending them.
+    ///
+    /// Useful for checking message templates during local development without a real workspace. The JSON that would
+    /// have been sent is returned by `dry_run_requests`; it is also logged at info level, but only with the `tracing`
+    /// feature.
+    pub fn with_dry_run(token: String) -> Self {
+        SlackClient {
+            dry_run: true,
+            ..SlackClient::new(token)
+        }
+    }
+
+    /// Create a new Slack API client that adds `headers` to every request, e.g. a header required by a corporate gateway.
+    ///
+    /// The authorization and content type headers set for each call are never overridden.
+    pub fn with_headers(token: String, headers: HeaderMap) -> Self {
+        SlackClient {
+            default_headers: headers,
+            ..SlackClient::new(token)
+        }
+    }
+
+    /// Create a new Slack API client that sends at most `max_concurrency` requests at once, across all methods and clones.
+    ///
+    /// Requests over the limit wait for an earlier one to finish, which helps fanning out without hitting rate limits.
+    pub fn with_max_concurrency(token: String, max_concurrency: usize) -> Self {
+        SlackClient {
+            limiter: Some(Arc::new(Semaphore::new(max_concurrency))),
+            ..SlackClient::new(token)
+        }
+    }
+
+    /// Create a new Slack API client around an existing reqwest client.
+    ///
+    /// All requests are sent through `client`, so its timeout, proxy, TLS, and redirect settings are respected.
+    /// Redirects that are not followed are reported as `SlackApiError::UnexpectedResponse`.
+    ///
+    /// Without the `blocking` feature, no Tokio runtime is created.
+    pub fn with_client(token: String, client: Client) -> Self {
+        let slack_client = SlackClient::from_parts(token, client);
+
+        #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+        let slack_client = SlackClient {
+            runtime: Some(Arc::new(Runtime::new().unwrap())),
+            ..slack_client
+        };
+
+        slack_client
+    }
+
+    /// Create a new Slack API client that runs its blocking methods on an existing Tokio runtime.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub fn with_runtime(token: String, runtime: Arc<Runtime>) -> Self {
+        SlackClient {
+            runtime: Some(runtime),
+            ..SlackClient::from_parts(token, default_client())
+        }
+    }
 
+    /// Create a new async-only Slack API client from its parts.
+    fn from_parts(token: String, client: Client) -> Self {
         SlackClient {
-            token,
-            client: Client::new(),
-            runtime,
+            token: Arc::from(token),
+            client,
+            #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+            runtime: None,
+            max_retries: 0,
+            base_url: SLACK_API_URL.to_string(),
+            retry_policy: None,
+            retry_budget: None,
+            default_channel: None,
+            dry_run: false,
+            default_headers: HeaderMap::new(),
+            limiter: None,
+            posted_once: Arc::default(),
+            upserted: Arc::default(),
+            dry_run_requests: Arc::default(),
+        }
+    }
+
+    /// Requests that were not sent because the client is in dry-run mode, oldest first, as the method name and the
+    /// JSON that would have been posted. Form requests are returned as an object of their fields.
+    pub fn dry_run_requests(&self) -> Vec<(String, Value)> {
+        self.dry_run_requests.lock().unwrap().clone()
+    }
+
+    /// Runs a future to completion on the client's runtime.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub(crate) fn block_on<T>(&self, future: impl Future<Output=Result<T, SlackApiError>>) -> Result<T, SlackApiError> {
+        block_on(self.runtime.as_deref(), future)
+    }
+
+    /// Posts a form-encoded request to a Slack API method and returns the response body.
+    pub(crate) async fn post_form<T: Serialize + Sync + ?Sized>(&self, method: &str, form: &T) -> Result<Value, SlackApiError> {
+        if let Some(body) = self.dry_run_response(method, form) {
+            return Ok(body);
+        }
+
+        let request = self.client.post(format!("{}/{}", self.base_url, method)).form(form);
+        self.send(method, request).await
+    }
+
+    /// Posts a form-encoded request to a Slack API method and returns the response body along with the captured response headers.
+    pub(crate) async fn post_form_with_headers<T: Serialize + Sync + ?Sized>(&self, method: &str, form: &T) -> Result<(Value, HashMap<String, String>), SlackApiError> {
+        if let Some(body) = self.dry_run_response(method, form) {
+            return Ok((body, HashMap::new()));
+        }
+
+        let request = self.client.post(format!("{}/{}", self.base_url, method)).form(form);
+        self.send_with_headers(method, request).await
+    }
+
+    /// Posts a form-encoded request to a Slack API method that times out after `timeout`, whatever the timeout of the client.
+    pub(crate) async fn post_form_with_timeout<T: Serialize + Sync + ?Sized>(&self, method: &str, form: &T, timeout: Duration) -> Result<Value, SlackApiError> {
+        if let Some(body) = self.dry_run_response(method, form) {
+            return Ok(body);
+        }
+
+        let request = self.client.post(format!("{}/{}", self.base_url, method)).form(form).timeout(timeout);
+        self.send(method, request).await
+    }
+
+    /// Posts a JSON request to a Slack API method and returns the response body.
+    pub(crate) async fn post_json<T: Serialize + Sync + ?Sized>(&self, method: &str, json: &T) -> Result<Value, SlackApiError> {
+        if let Some(body) = self.dry_run_response(method, json) {
+            return Ok(body);
+        }
+
+        let request = self.client.post(format!("{}/{}", self.base_url, method)).json(json);
+        self.send(method, request).await
+    }
+
+    /// Posts a JSON request to a Slack API method and returns the response body along with the captured response headers.
+    pub(crate) async fn post_json_with_headers<T: Serialize + Sync + ?Sized>(&self, method: &str, json: &T) -> Result<(Value, HashMap<String, String>), SlackApiError> {
+        if let Some(body) = self.dry_run_response(method, json) {
+            return Ok((body, HashMap::new()));
+        }
+
+        let request = self.client.post(format!("{}/{}", self.base_url, method)).json(json);
+        self.send_with_headers(method, request).await
+    }
+
+    /// Sends a GET request with query parameters to a Slack API method and returns the response body.
+    pub(crate) async fn get_query<T: Serialize + Sync + ?Sized>(&self, method: &str, query: &T) -> Result<Value, SlackApiError> {
+        let request = self.client.get(format!("{}/{}", self.base_url, method)).query(query);
+        self.send(method, request).await
+    }
+
+    /// In dry-run mode, record the request of a method that would change messages and return a synthetic response instead of sending it.
+    ///
+    /// The response echoes the request as the message, with the ts `DRY_RUN_TS`.
+    fn dry_run_response<T: Serialize + ?Sized>(&self, method: &str, payload: &T) -> Option<Value> {
+        if !self.dry_run || !DRY_RUN_METHODS.contains(&method) {
+            return None;
+        }
+
+        // Form fields serialize as a list of name and value pairs
+        let payload = match serde_json::to_value(payload).unwrap_or_default() {
+            Value::Array(pairs) => Value::Object(pairs.iter()
+                .filter_map(|pair| Some((pair[0].as_str()?.to_string(), pair[1].clone())))
+                .collect::<Map<String, Value>>()),
+            payload => payload,
+        };
+
+        self.record_dry_run(method, payload.clone());
+
+        let mut message = payload.clone();
+        if let Value::Object(ref mut fields) = message {
+            fields.insert("ts".to_string(), json!(DRY_RUN_TS));
+        }
+        Some(json!({
+            "ok": true,
+            "channel": payload["channel"].as_str().unwrap_or_default(),
+            "ts": DRY_RUN_TS,
+            "message_ts": DRY_RUN_TS,
+            "scheduled_message_id": "Q0000000000",
+            "message": message,
+        }))
+    }
+
+    /// Record a request not sent in dry-run mode, and log it with the `tracing` feature.
+    pub(crate) fn record_dry_run(&self, method: &str, payload: Value) {
+        #[cfg(feature = "tracing")]
+        tracing::info!(method, payload = %payload, "dry run, request not sent");
+
+        self.dry_run_requests.lock().unwrap().push((method.to_string(), payload));
+    }
+
+    /// Sends a request with the default headers added, without overriding the headers already set on it.
+    pub(crate) async fn execute(&self, request: RequestBuilder) -> Result<Response, SlackApiError> {
+        let mut request = request.build().map_err(SlackApiError::from)?;
+
+        let missing: Vec<_> = self.default_headers.iter()
+            .filter(|(name, _)| !request.headers().contains_key(*name))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        for (name, value) in missing {
+            request.headers_mut().append(name, value);
+        }
+
+        // Wait for a free slot if the number of requests in flight is limited
+        let _permit = match &self.limiter {
+            Some(limiter) => Some(limiter.acquire().await.map_err(|e| SlackApiError::RuntimeError(e.to_string()))?),
+            None => None,
+        };
+
+        self.client.execute(request).await.map_err(SlackApiError::from)
+    }
+
+    /// Sends an authenticated request for a Slack API method and parses the response body.
+    async fn send(&self, method: &str, request: RequestBuilder) -> Result<Value, SlackApiError> {
+        self.send_with_headers(method, request).await.map(|(body, _)| body)
+    }
+
+    /// Sends an authenticated request for a Slack API method and parses the response body, keeping the captured response headers.
+    async fn send_with_headers(&self, method: &str, request: RequestBuilder) -> Result<(Value, HashMap<String, String>), SlackApiError> {
+        let attempts = self.send_attempts(method, request);
+
+        #[cfg(feature = "tracing")]
+        let attempts = traced(method, attempts);
+
+        assert_send(attempts).await
+    }
+
+    /// Sends a request until it succeeds or runs out of retries.
+    async fn send_attempts(&self, method: &str, request: RequestBuilder) -> Result<(Value, HashMap<String, String>), SlackApiError> {
+        let mut retries = 0;
+        let mut server_error_retries = 0;
+
+        loop {
+            let attempt = request.try_clone()
+                .ok_or(SlackApiError::InvalidArgument("Request body cannot be retried".into()))?;
+            let res = self.execute(attempt.bearer_auth(&self.token)).await.map_err(|e| e.in_method(method))?;
+
+            // Retry rate limited requests after the delay requested by Slack, or report the delay once out of retries
+            if res.status() == StatusCode::TOO_MANY_REQUESTS {
+                let retry_after = retry_after(&res);
+                if !CAN_WAIT || retries >= self.max_retries || !self.withdraw_retry() {
+                    return Err(SlackApiError::RateLimited { method: method.to_string(), retry_after });
+                }
+
+                retries += 1;
+                tokio::time::sleep(retry_after).await;
+                continue;
+            }
+
+            // Retry server errors with exponential backoff if the policy allows it
+            if res.status().is_server_error() {
+                if let Some(policy) = self.retry_policy.filter(|policy| CAN_WAIT && policy.allows(method)) {
+                    if server_error_retries < policy.max_retries && self.withdraw_retry() {
+                        tokio::time::sleep(policy.backoff(server_error_retries)).await;
+                        server_error_retries += 1;
+                        continue;
+                    }
+                }
+            }
+
+            // A redirect, e.g. from a proxy to its login page, is not a Slack API response
+            if res.status().is_redirection() {
+                let location = res.headers().get(reqwest::header::LOCATION).and_then(|value| value.to_str().ok()).unwrap_or_default();
+                return Err(SlackApiError::UnexpectedResponse { method: method.to_string(), status: res.status().as_u16(), snippet: format!("Redirect to {}", location) });
+            }
+
+            let res = check_status(method, res).await?;
+            let headers = captured_headers(&res);
+            let body = parse_response(method, res).await?;
+            if let Some(budget) = &self.retry_budget {
+                budget.deposit();
+            }
+            return Ok((body, headers));
         }
     }
-}
\ No newline at end of file
+
+    /// Take a retry from the retry budget, if there is one, returning false once it is exhausted.
+    fn withdraw_retry(&self) -> bool {
+        self.retry_budget.as_ref().is_none_or(|budget| budget.withdraw())
+    }
+}
+
+/// Run the request of a Slack API method in a span, logging its latency at debug level and failures at warn level.
+///
+/// Only the method name is recorded, never the token or the request body.
+#[cfg(feature = "tracing")]
+async fn traced<T>(method: &str, future: impl std::future::Future<Output=Result<T, SlackApiError>>) -> Result<T, SlackApiError> {
+    use tracing::Instrument;
+
+    // wasm32 has no clock to measure the latency with
+    #[cfg(not(target_arch = "wasm32"))]
+    let started = Some(std::time::Instant::now());
+    #[cfg(target_arch = "wasm32")]
+    let started: Option<std::time::Instant> = None;
+
+    async move {
+        tracing::debug!("sending request");
+        let result = future.await;
+        let elapsed_ms = started.map(|started| started.elapsed().as_millis() as u64);
+        match &result {
+            Ok(_) => tracing::debug!(elapsed_ms, "request succeeded"),
+            Err(e) => tracing::warn!(elapsed_ms, error = %e, code = e.code(), "request failed"),
+        }
+        result
+    }.instrument(tracing::debug_span!("slack_api", method)).await
+}
+
+/// Run the future of a chat method in a span recording the channel it targets.
+#[cfg(feature = "tracing")]
+pub(crate) fn in_channel<F: std::future::Future>(channel: &str, future: F) -> tracing::instrument::Instrumented<F> {
+    tracing::Instrument::instrument(future, tracing::debug_span!("chat", channel))
+}
+
+/// Run the future of a chat method as is, without the `tracing` feature.
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn in_channel<F: std::future::Future>(_channel: &str, future: F) -> F {
+    future
+}
+
+/// Make the future of a request Send on WASM targets, whose fetch futures are not Send but always run on a single thread.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn assert_send<F: std::future::Future>(future: F) -> send_wrapper::SendWrapper<F> {
+    send_wrapper::SendWrapper::new(future)
+}
+
+/// Return the future of a request as is, on targets whose HTTP futures are already Send.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn assert_send<F: std::future::Future>(future: F) -> F {
+    future
+}
+
+/// Create a reqwest client builder using the TLS backend selected by the `native-tls` or `rustls-tls` feature.
+///
+/// Exactly one of them is enabled, which src/lib.rs checks. On WASM targets the browser's fetch API handles TLS.
+pub(crate) fn tls_client_builder() -> ClientBuilder {
+    #[cfg(all(feature = "rustls-tls", not(target_arch = "wasm32")))]
+    return Client::builder().use_rustls_tls();
+
+    #[cfg(any(not(feature = "rustls-tls"), target_arch = "wasm32"))]
+    Client::builder()
+}
+
+/// Create a reqwest client builder with the defaults of the crate.
+///
+/// Redirects are not followed, so a proxy redirecting to a login page surfaces as `SlackApiError::UnexpectedResponse`.
+/// On WASM targets the fetch API follows them.
+fn client_builder() -> ClientBuilder {
+    #[cfg(not(target_arch = "wasm32"))]
+    return tls_client_builder().redirect(Policy::none());
+
+    #[cfg(target_arch = "wasm32")]
+    tls_client_builder()
+}
+
+/// Create a reqwest client with the defaults of the crate.
+fn default_client() -> Client {
+    client_builder().build().unwrap()
+}
+
+/// Runs a future to completion on `runtime`, failing if there is none or if called from within an async context.
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub(crate) fn block_on<T>(runtime: Option<&Runtime>, future: impl Future<Output=Result<T, SlackApiError>>) -> Result<T, SlackApiError> {
+    check_not_async()?;
+
+    match runtime {
+        Some(runtime) => runtime.block_on(future),
+        None => Err(SlackApiError::RuntimeError("Cannot call a blocking method on an async-only client, use the async method instead".into())),
+    }
+}
+
+/// Runs a future to completion on a new current-thread runtime, failing if called from within an async context.
+///
+/// Used by the default bodies of the blocking trait methods, so implementations of the traits only need the async
+/// methods and keep compiling when another crate enables the `blocking` feature.
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub(crate) fn block_on_current_thread<T>(future: impl Future<Output=Result<T, SlackApiError>>) -> Result<T, SlackApiError> {
+    check_not_async()?;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| SlackApiError::RuntimeError(e.to_string()))?;
+    runtime.block_on(future)
+}
+
+/// Blocking inside an async context would panic, so report it as an error instead.
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+fn check_not_async() -> Result<(), SlackApiError> {
+    if Handle::try_current().is_ok() {
+        return Err(SlackApiError::RuntimeError("Cannot call a blocking method from within an async runtime, use the async method instead".into()));
+    }
+
+    Ok(())
+}
+
+/// Seconds since the Unix epoch, or `None` on wasm32, which has no system clock.
+pub(crate) fn unix_now() -> Option<i64> {
+    if cfg!(target_arch = "wasm32") {
+        return None;
+    }
+
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs() as i64).ok()
+}
+
+/// Read the Retry-After header of a rate limited response, defaulting to one second.
+fn retry_after(res: &Response) -> Duration {
+    let seconds = res.headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .unwrap_or(1);
+
+    Duration::from_secs(seconds)
+}
+
+/// Collect the response headers listed in CAPTURED_HEADERS that are present and valid strings.
+fn captured_headers(res: &Response) -> HashMap<String, String> {
+    CAPTURED_HEADERS.iter()
+        .filter_map(|name| {
+            let value = res.headers().get(*name)?.to_str().ok()?;
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Turn an HTTP error status of `method` into a SlackApiError, keeping the status code and the start of the body.
+pub(crate) async fn check_status(method: &str, res: Response) -> Result<Response, SlackApiError> {
+    let status = res.status();
+    if !status.is_client_error() && !status.is_server_error() {
+        return Ok(res);
+    }
+
+    let text = res.text().await.map_err(|e| SlackApiError::from(e).in_method(method))?;
+    Err(SlackApiError::HttpStatus { method: method.to_string(), status: status.as_u16(), snippet: text.chars().take(SNIPPET_LEN).collect() })
+}
+
+/// Parse the body of a Slack API method response, surfacing `ok: false` as a SlackApiError.
+pub(crate) async fn parse_response(method: &str, res: Response) -> Result<Value, SlackApiError> {
+    let status = res.status().as_u16();
+    let text = res.text().await.map_err(|e| SlackApiError::from(e).in_method(method))?;
+
+    // Anything but a JSON object, e.g. an HTML error page during an outage, is not a Slack API response
+    let body: Value = match serde_json::from_str(&text) {
+        Ok(body @ Value::Object(_)) => body,
+        _ => return Err(SlackApiError::UnexpectedResponse { method: method.to_string(), status, snippet: text.chars().take(SNIPPET_LEN).collect() }),
+    };
+
+    if body["ok"].as_bool().unwrap_or(false) {
+        Ok(body)
+    } else {
+        Err(SlackApiError::from_response(method, status, body))
+    }
+}
+
+/// Decode a Slack API response body into a typed response struct.
+pub(crate) fn decode_response<T: DeserializeOwned>(body: Value) -> Result<T, SlackApiError> {
+    serde_json::from_value(body).map_err(|e| SlackApiError::InvalidArgument(format!("Invalid response: {}", e)))
+}
+
+/// Read the cursor of the next page from a paginated response, if there is one.
+pub(crate) fn next_cursor(body: &Value) -> Option<String> {
+    body["response_metadata"]["next_cursor"]
+        .as_str()
+        .filter(|cursor| !cursor.is_empty())
+        .map(String::from)
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod slack_client_tests {
+    use serde_json::json;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::Chat;
+
+    use super::*;
+
+    #[test]
+    fn slack_client_clone_shares_state() {
+        let client = SlackClient::new("xoxb-test".to_string());
+        let clone = client.clone();
+
+        // Clones share the token and runtime instead of copying them
+        assert!(Arc::ptr_eq(&client.token, &clone.token));
+        assert!(Arc::ptr_eq(client.runtime.as_ref().unwrap(), clone.runtime.as_ref().unwrap()));
+    }
+
+    #[test]
+    fn slack_client_try_new_validates_token() {
+        assert!(SlackClient::try_new("xoxb-test".to_string()).is_ok());
+        assert!(SlackClient::try_new("xoxp-test".to_string()).is_ok());
+        assert!(SlackClient::try_new("xapp-test".to_string()).is_ok());
+
+        for token in ["", "xoxb-", "xoxa-test", "test"] {
+            let client = SlackClient::try_new(token.to_string());
+            assert!(matches!(client, Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error for {:?}", token);
+        }
+    }
+
+    #[test]
+    fn slack_client_from_env() {
+        // The token is only available when the live tests are configured
+        let client = SlackClient::from_env();
+        match env::var("SLACK_TOKEN") {
+            Ok(token) => assert_eq!(&*client.unwrap().token, token),
+            Err(_) => assert_eq!(client.err(), Some(SlackApiError::InvalidArgument("SLACK_TOKEN is not set".into()))),
+        }
+    }
+
+    #[test]
+    fn slack_client_new_async_has_no_runtime() {
+        let client = SlackClient::new_async("xoxb-test".to_string());
+        assert!(client.runtime.is_none());
+
+        // Blocking methods need a runtime
+        let post = client.post_message_text("C0000000000".to_string(), "Hello, Slack from Rust!".to_string());
+        assert!(matches!(post, Err(SlackApiError::RuntimeError(_))), "Expected a runtime error");
+    }
+
+    #[test]
+    fn slack_client_with_base_url() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .and(header("authorization", "Bearer xoxb-test"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "channel": "C0000000000",
+                    "ts": "1700000000.000100",
+                    "message": { "text": "Hello, Slack from Rust!" },
+                })))
+                .mount(&server)
+        );
+
+        // Requests go to the mock server instead of Slack
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let post = client.post_message_text("C0000000000", "Hello, Slack from Rust!");
+        assert_eq!(post.unwrap(), "1700000000.000100");
+    }
+
+    #[test]
+    fn slack_client_with_headers() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .and(header("x-internal-request-id", "42"))
+                .and(header("authorization", "Bearer xoxb-test"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })))
+                .mount(&server)
+        );
+
+        // The default headers are added, but the bearer token of the client wins
+        let mut headers = HeaderMap::new();
+        headers.insert("x-internal-request-id", "42".parse().unwrap());
+        headers.insert(reqwest::header::AUTHORIZATION, "Bearer xoxb-other".parse().unwrap());
+        let client = SlackClient {
+            base_url: server.uri(),
+            ..SlackClient::with_headers("xoxb-test".to_string(), headers)
+        };
+        let delete = client.delete("C0000000000".to_string(), "1700000000.000100".to_string());
+        assert!(delete.is_ok(), "Expected the default headers to be sent");
+    }
+
+    #[test]
+    fn slack_client_with_max_concurrency() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })).set_delay(Duration::from_millis(100)))
+                .mount(&server)
+        );
+
+        // With one request at a time, the deletes run one after the other
+        let client = SlackClient {
+            base_url: server.uri(),
+            ..SlackClient::with_max_concurrency("xoxb-test".to_string(), 1)
+        };
+        let timestamps = vec!["1700000000.000100".to_string(), "1700000000.000200".to_string(), "1700000000.000300".to_string()];
+        let started = std::time::Instant::now();
+        let deletes = client.delete_many("C0000000000".to_string(), timestamps).unwrap();
+        assert!(deletes.iter().all(|delete| delete.is_ok()));
+        assert!(started.elapsed() >= Duration::from_millis(300), "Expected the requests to be serialized");
+    }
+
+    #[test]
+    fn slack_client_surfaces_slack_error() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "channel_not_found" })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let delete = client.delete("C0000000000".to_string(), "1700000000.000100".to_string());
+        match delete {
+            Err(SlackApiError::SlackError { method, code, detail }) => {
+                assert_eq!(method, "chat.delete");
+                assert_eq!(code, "channel_not_found");
+                assert!(detail.unwrap().contains("channel_not_found"));
+            }
+            other => panic!("Expected a Slack error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn slack_client_rejects_non_json_response() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        let page = format!("<html><body>{}</body></html>", "Slack is down ".repeat(30));
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .respond_with(ResponseTemplate::new(200).set_body_string(page.clone()))
+                .mount(&server)
+        );
+
+        // The error keeps the status and the beginning of the body
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let delete = client.delete("C0000000000".to_string(), "1700000000.000100".to_string());
+        match delete {
+            Err(SlackApiError::UnexpectedResponse { method, status, snippet }) => {
+                assert_eq!(method, "chat.delete");
+                assert_eq!(status, 200);
+                assert_eq!(snippet, page[..200]);
+            }
+            other => panic!("Expected an unexpected response error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn slack_client_does_not_follow_redirects() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .respond_with(ResponseTemplate::new(302).insert_header("location", format!("{}/login", server.uri())))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/login"))
+                .respond_with(ResponseTemplate::new(200).set_body_string("<html>Sign in</html>"))
+                .expect(0)
+                .mount(&server)
+        );
+
+        // The redirect is reported instead of followed
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let delete = client.delete("C0000000000".to_string(), "1700000000.000100".to_string());
+        assert_eq!(delete, Err(SlackApiError::UnexpectedResponse { method: "chat.delete".to_string(), status: 302, snippet: format!("Redirect to {}/login", server.uri()) }));
+    }
+
+    #[test]
+    fn slack_client_retries_server_errors() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        for path_name in ["/chat.delete", "/chat.postMessage"] {
+            runtime.block_on(
+                Mock::given(method("POST"))
+                    .and(path(path_name))
+                    .respond_with(ResponseTemplate::new(503))
+                    .up_to_n_times(1)
+                    .with_priority(1)
+                    .mount(&server)
+            );
+            runtime.block_on(
+                Mock::given(method("POST"))
+                    .and(path(path_name))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true, "channel": "C0000000000", "ts": "1700000000.000100", "message": {} })))
+                    .mount(&server)
+            );
+        }
+
+        let mut client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        client.retry_policy = Some(RetryPolicy { base_delay: Duration::from_millis(1), ..Default::default() });
+
+        // Idempotent methods are retried after a server error
+        let delete = client.delete("C0000000000".to_string(), "1700000000.000100".to_string());
+        assert!(delete.is_ok(), "Failed to retry server error");
+
+        // Posting a message is not retried unless opted in
+        let post = client.post_message_text("C0000000000".to_string(), "Hello, Slack from Rust!".to_string());
+        assert!(matches!(post, Err(SlackApiError::HttpStatus { status: 503, .. })), "Expected an HTTP error");
+    }
+
+    #[test]
+    fn slack_client_reports_http_status() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.postMessage"))
+                .respond_with(ResponseTemplate::new(403).set_body_string("Forbidden by proxy"))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let post = client.post_message_text("C0000000000".to_string(), "Hello, Slack from Rust!".to_string());
+        assert_eq!(post, Err(SlackApiError::HttpStatus { method: "chat.postMessage".to_string(), status: 403, snippet: "Forbidden by proxy".to_string() }));
+        assert_eq!(post.unwrap_err().to_string(), "chat.postMessage: HTTP status 403: Forbidden by proxy");
+    }
+
+    #[test]
+    fn slack_client_distinguishes_network_errors() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })).set_delay(Duration::from_secs(5)))
+                .mount(&server)
+        );
+
+        // A slow response times out
+        let client = SlackClient { base_url: server.uri(), ..SlackClient::with_timeout("xoxb-test".to_string(), Duration::from_millis(100)).unwrap() };
+        let delete = client.delete("C0000000000", "1700000000.000100");
+        assert!(matches!(delete, Err(SlackApiError::Timeout { ref method, .. }) if method == "chat.delete"), "Expected a timeout error, got {:?}", delete);
+
+        // Nothing listening on the port is a connection error
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), "http://127.0.0.1:1".to_string());
+        let delete = client.delete("C0000000000", "1700000000.000100");
+        assert!(matches!(delete, Err(SlackApiError::Connection { ref method, .. }) if method == "chat.delete"), "Expected a connection error, got {:?}", delete);
+    }
+
+    #[test]
+    fn slack_client_retries_rate_limited_requests() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+                .up_to_n_times(1)
+                .with_priority(1)
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })))
+                .mount(&server)
+        );
+
+        // The first attempt is rate limited and the retry succeeds
+        let mut client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        client.max_retries = 1;
+        let delete = client.delete("C0000000000".to_string(), "1700000000.000100".to_string());
+        assert!(delete.is_ok(), "Failed to retry rate limited request");
+    }
+
+    #[test]
+    fn slack_client_retry_budget_stops_retries() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .respond_with(ResponseTemplate::new(503))
+                .expect(4)
+                .mount(&server)
+        );
+
+        let client = SlackClient {
+            base_url: server.uri(),
+            retry_policy: Some(RetryPolicy { max_retries: 5, base_delay: Duration::from_millis(1), ..Default::default() }),
+            ..SlackClient::with_retry_budget("xoxb-test".to_string(), RetryBudget::new(2, 0.1))
+        };
+
+        // The budget allows two retries even though the policy allows five
+        let delete = client.delete("C0000000000", "1700000000.000100");
+        assert!(matches!(delete, Err(SlackApiError::HttpStatus { status: 503, .. })), "Expected an HTTP error");
+        assert_eq!(client.retry_budget.as_ref().unwrap().available(), 0.0);
+
+        // Clones share the exhausted budget, so they send a single attempt
+        let delete = client.clone().delete("C0000000000", "1700000000.000100");
+        assert!(delete.is_err(), "Expected an HTTP error");
+    }
+
+    #[test]
+    fn slack_client_reports_rate_limit_delay() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.delete"))
+                .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "30"))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/chat.update"))
+                .respond_with(ResponseTemplate::new(429))
+                .mount(&server)
+        );
+
+        // Without retries the delay requested by Slack is returned right away
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let delete = client.delete("C0000000000".to_string(), "1700000000.000100".to_string());
+        assert_eq!(delete.unwrap_err(), SlackApiError::RateLimited { method: "chat.delete".to_string(), retry_after: Duration::from_secs(30) });
+
+        // A missing Retry-After header falls back to one second
+        let update = client.update(crate::ChatUpdateArguments {
+            channel: "C0000000000".to_string(),
+            ts: "1700000000.000100".to_string(),
+            text: Some("Hello, Slack from Rust!".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(update.unwrap_err(), SlackApiError::RateLimited { method: "chat.update".to_string(), retry_after: Duration::from_secs(1) });
+    }
+}
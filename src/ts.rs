@@ -0,0 +1,127 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::SlackApiError;
+
+/// Timestamp of a message, like `1700000000.000100`, which Slack uses as the ID of the message in its channel.
+///
+/// Parsing checks the shape of the timestamp, which catches passing a channel or user ID where a ts is
+/// expected. A `Ts` converts into a `String`, so it can be given to every method taking a ts.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct Ts {
+    seconds: u64,
+    micros: u32,
+}
+
+impl Ts {
+    /// Create a timestamp from the seconds and microseconds since the Unix epoch.
+    ///
+    /// Returns `SlackApiError::InvalidArgument` if `micros` is a second or more.
+    pub fn new(seconds: u64, micros: u32) -> Result<Self, SlackApiError> {
+        if micros >= 1_000_000 {
+            return Err(SlackApiError::InvalidArgument(format!("ts microseconds out of range: {}", micros)));
+        }
+        Ok(Ts { seconds, micros })
+    }
+
+    /// Seconds since the Unix epoch.
+    pub fn seconds(&self) -> u64 {
+        self.seconds
+    }
+
+    /// Microseconds within the second, which tell apart the messages posted in the same second.
+    pub fn micros(&self) -> u32 {
+        self.micros
+    }
+}
+
+impl FromStr for Ts {
+    type Err = SlackApiError;
+
+    fn from_str(ts: &str) -> Result<Self, Self::Err> {
+        let invalid = || SlackApiError::InvalidArgument(format!("not a message ts: {}", ts));
+
+        let (seconds, micros) = ts.split_once('.').ok_or_else(invalid)?;
+        if seconds.is_empty() || micros.len() != 6 || !seconds.bytes().chain(micros.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        Ok(Ts {
+            seconds: seconds.parse().map_err(|_| invalid())?,
+            micros: micros.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl fmt::Display for Ts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{:06}", self.seconds, self.micros)
+    }
+}
+
+impl TryFrom<String> for Ts {
+    type Error = SlackApiError;
+
+    fn try_from(ts: String) -> Result<Self, Self::Error> {
+        ts.parse()
+    }
+}
+
+impl From<Ts> for String {
+    fn from(ts: Ts) -> Self {
+        ts.to_string()
+    }
+}
+
+impl From<Ts> for SystemTime {
+    fn from(ts: Ts) -> Self {
+        UNIX_EPOCH + Duration::from_secs(ts.seconds) + Duration::from_micros(ts.micros as u64)
+    }
+}
+
+impl TryFrom<SystemTime> for Ts {
+    type Error = SlackApiError;
+
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let since_epoch = time.duration_since(UNIX_EPOCH).map_err(|_| SlackApiError::InvalidArgument("ts before the Unix epoch".into()))?;
+        Ok(Ts { seconds: since_epoch.as_secs(), micros: since_epoch.subsec_micros() })
+    }
+}
+
+
+#[cfg(test)]
+mod ts_tests {
+    use super::*;
+
+    #[test]
+    fn ts_parse_and_format() {
+        let ts: Ts = "1700000000.000100".parse().unwrap();
+        assert_eq!((ts.seconds(), ts.micros()), (1700000000, 100));
+        assert_eq!(ts.to_string(), "1700000000.000100");
+        assert_eq!(String::from(ts), "1700000000.000100");
+
+        // Channel IDs and other strings are rejected
+        for ts in ["C0000000000", "1700000000", "1700000000.100", ".000100", "1700000000.00010a"] {
+            assert!(matches!(ts.parse::<Ts>(), Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error for {}", ts);
+        }
+    }
+
+    #[test]
+    fn ts_system_time() {
+        let ts = Ts::new(1700000000, 100).unwrap();
+        let time = SystemTime::from(ts);
+        assert_eq!(time, UNIX_EPOCH + Duration::from_secs(1700000000) + Duration::from_micros(100));
+        assert_eq!(Ts::try_from(time), Ok(ts));
+    }
+
+    #[test]
+    fn ts_serde() {
+        let ts: Ts = serde_json::from_str("\"1700000000.000100\"").unwrap();
+        assert_eq!(serde_json::to_string(&ts).unwrap(), "\"1700000000.000100\"");
+        assert!(serde_json::from_str::<Ts>("\"C0000000000\"").is_err());
+    }
+}
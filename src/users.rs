@@ -0,0 +1,201 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::errors::SlackApiError;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use crate::slack_client::block_on_current_thread;
+use crate::slack_client::decode_response;
+use crate::SlackClient;
+
+/// A user in a Slack team.
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct User {
+    /// ID of the user.
+    pub id: String,
+    /// Username of the user.
+    #[serde(default)]
+    pub name: String,
+    /// Full name of the user.
+    #[serde(default)]
+    pub real_name: Option<String>,
+    /// Display name of the user, taken from the user's profile. `None` if the user has not set one.
+    #[serde(default)]
+    pub display_name: Option<String>,
+    /// Time zone of the user, e.g. `America/New_York`.
+    #[serde(default)]
+    pub tz: Option<String>,
+    /// Whether the user is a bot.
+    #[serde(default)]
+    pub is_bot: bool,
+}
+
+/// Users trait for the Slack API client.
+pub trait Users: Send + Sync {
+    /// Finds a user with an email address.
+    ///
+    /// Returns `SlackApiError::UsersNotFound` if no user has the email address.
+    ///
+    /// <https://api.slack.com/methods/users.lookupByEmail>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn lookup_by_email(&self, email: impl Into<String>) -> Result<User, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.lookup_by_email_async(email))
+    }
+
+    /// Finds a user with an email address asynchronously.
+    ///
+    /// <https://api.slack.com/methods/users.lookupByEmail>
+    fn lookup_by_email_async(&self, email: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<User, SlackApiError>> + Send + '_>> where Self: Sized;
+
+    /// Gets the details of a user by ID.
+    ///
+    /// Returns `SlackApiError::UserNotFound` if no user has the ID.
+    ///
+    /// <https://api.slack.com/methods/users.info>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn info(&self, user_id: impl Into<String>) -> Result<User, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.info_async(user_id))
+    }
+
+    /// Gets the details of a user by ID asynchronously.
+    ///
+    /// <https://api.slack.com/methods/users.info>
+    fn info_async(&self, user_id: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<User, SlackApiError>> + Send + '_>> where Self: Sized;
+}
+
+/// Implement the Users trait for SlackClient.
+impl Users for SlackClient {
+    /// Finds a user with an email address.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn lookup_by_email(&self, email: impl Into<String>) -> Result<User, SlackApiError> {
+        self.block_on(self.lookup_by_email_async(email))
+    }
+
+    /// Finds a user with an email address asynchronously.
+    fn lookup_by_email_async(&self, email: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<User, SlackApiError>> + Send + '_>> {
+        let email: String = email.into();
+
+        Box::pin(async move {
+            let body = self.get_query("users.lookupByEmail", &[("email", &email)]).await?;
+            decode_user(&body["user"])
+        })
+    }
+
+    /// Gets the details of a user by ID.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn info(&self, user_id: impl Into<String>) -> Result<User, SlackApiError> {
+        self.block_on(self.info_async(user_id))
+    }
+
+    /// Gets the details of a user by ID asynchronously.
+    fn info_async(&self, user_id: impl Into<String>) -> Pin<Box<dyn Future<Output=Result<User, SlackApiError>> + Send + '_>> {
+        let user_id: String = user_id.into();
+
+        Box::pin(async move {
+            let body = self.get_query("users.info", &[("user", &user_id)]).await?;
+            decode_user(&body["user"])
+        })
+    }
+}
+
+/// Decode a user object, lifting the display name out of its profile.
+fn decode_user(user: &Value) -> Result<User, SlackApiError> {
+    let mut decoded: User = decode_response(user.clone())?;
+    decoded.display_name = user["profile"]["display_name"]
+        .as_str()
+        .filter(|display_name| !display_name.is_empty())
+        .map(String::from);
+    Ok(decoded)
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod users_tests {
+    use std::env;
+
+    use serde_json::json;
+    use tokio::runtime::Runtime;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn users_lookup_by_email() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let email = env::var("SLACK_USER_EMAIL").expect("Expected a user email in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Look up the user by email
+        let lookup = client.lookup_by_email(email);
+        assert!(lookup.is_ok(), "Failed to look up user");
+        assert!(!lookup.unwrap().id.is_empty(), "Missing user ID");
+    }
+
+    #[test]
+    fn users_lookup_by_unknown_email() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Look up an email address nobody has
+        let lookup = client.lookup_by_email("nobody@example.invalid".to_string());
+        assert!(matches!(lookup, Err(SlackApiError::UsersNotFound)), "Expected a users not found error");
+    }
+
+    #[test]
+    fn users_info() {
+        let token = env::var("SLACK_TOKEN").expect("Expected a token in the environment");
+        let user_id = env::var("SLACK_USER_ID").expect("Expected a user id in the environment");
+
+        let client = SlackClient::new(token.to_string());
+
+        // Get the user by ID
+        let info = client.info(user_id.to_string());
+        assert!(info.is_ok(), "Failed to get user info");
+        assert_eq!(info.unwrap().id, user_id);
+    }
+
+    #[test]
+    fn users_info_from_mock() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/users.info"))
+                .and(query_param("user", "U0000000001"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "user": {
+                        "id": "U0000000001",
+                        "name": "nobuo",
+                        "real_name": "Nobuo Miura",
+                        "tz": "Asia/Tokyo",
+                        "is_bot": false,
+                        "profile": { "display_name": "nobuo.m" },
+                    },
+                })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(path("/users.info"))
+                .and(query_param("user", "U0000000002"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "user_not_found" })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let user = client.info("U0000000001".to_string()).unwrap();
+        assert_eq!(user.display_name.as_deref(), Some("nobuo.m"));
+        assert_eq!(user.tz.as_deref(), Some("Asia/Tokyo"));
+        assert!(!user.is_bot);
+
+        // An unknown ID is reported with a typed error
+        let user = client.info("U0000000002".to_string());
+        assert_eq!(user.unwrap_err(), SlackApiError::UserNotFound);
+    }
+}
@@ -0,0 +1,100 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use serde_json::{json, Value};
+
+use crate::errors::SlackApiError;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use crate::slack_client::block_on_current_thread;
+use crate::SlackClient;
+
+/// Views trait for the Slack API client.
+pub trait Views: Send + Sync {
+    /// Opens a modal built from Block Kit `view` JSON in response to an interaction, and returns the ID of the view.
+    ///
+    /// `trigger_id` comes from the slash command or interaction payload and expires after 3 seconds, in which
+    /// case `SlackApiError::ExpiredTriggerId` is returned.
+    ///
+    /// <https://api.slack.com/methods/views.open>
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn open(&self, trigger_id: impl Into<String>, view: Value) -> Result<String, SlackApiError> where Self: Sized {
+        block_on_current_thread(self.open_async(trigger_id, view))
+    }
+
+    /// Opens a modal asynchronously, and returns the ID of the view.
+    ///
+    /// <https://api.slack.com/methods/views.open>
+    fn open_async(&self, trigger_id: impl Into<String>, view: Value) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> where Self: Sized;
+}
+
+/// Implement the Views trait for SlackClient.
+impl Views for SlackClient {
+    /// Opens a modal.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    fn open(&self, trigger_id: impl Into<String>, view: Value) -> Result<String, SlackApiError> {
+        self.block_on(self.open_async(trigger_id, view))
+    }
+
+    /// Opens a modal asynchronously.
+    fn open_async(&self, trigger_id: impl Into<String>, view: Value) -> Pin<Box<dyn Future<Output=Result<String, SlackApiError>> + Send + '_>> {
+        let trigger_id: String = trigger_id.into();
+
+        if trigger_id.is_empty() {
+            return Box::pin(async { Err(SlackApiError::InvalidArgument("trigger_id is required".into())) });
+        }
+        if !view.is_object() {
+            return Box::pin(async { Err(SlackApiError::InvalidArgument("view must be a JSON object".into())) });
+        }
+
+        Box::pin(async move {
+            let body = self.post_json("views.open", &json!({ "trigger_id": trigger_id, "view": view })).await?;
+
+            // Extract the view ID from the JSON
+            let id = body["view"]["id"].as_str().ok_or(SlackApiError::InvalidArgument("No view ID in response".into()))?.to_string();
+
+            Ok(id)
+        })
+    }
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod views_tests {
+    use tokio::runtime::Runtime;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn views_open() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/views.open"))
+                .and(body_partial_json(json!({ "trigger_id": "12345.98765.abcd" })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true, "view": { "id": "V0000000001" } })))
+                .mount(&server)
+        );
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/views.open"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": false, "error": "expired_trigger_id" })))
+                .mount(&server)
+        );
+
+        let client = SlackClient::with_base_url("xoxb-test".to_string(), server.uri());
+        let view = json!({
+            "type": "modal",
+            "title": { "type": "plain_text", "text": "Deploy" },
+            "blocks": [],
+        });
+        let open = client.open("12345.98765.abcd", view.clone());
+        assert_eq!(open, Ok("V0000000001".to_string()));
+
+        // A stale trigger ID returns a typed error
+        let open = client.open("12345.00000.dcba", view);
+        assert_eq!(open, Err(SlackApiError::ExpiredTriggerId));
+    }
+}
@@ -0,0 +1,166 @@
+use std::future::Future;
+use std::pin::Pin;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use std::sync::Arc;
+
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use tokio::runtime::Runtime;
+
+use crate::chat::{validate_content, ChatPostMessageAttachment};
+use crate::errors::SlackApiError;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+use crate::slack_client::block_on;
+use crate::slack_client::{assert_send, tls_client_builder};
+
+/// Payload of a message sent to an incoming webhook.
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+pub struct WebhookPayload {
+    /// Text of the message to send. This field is usually required, unless you're providing only `attachments` or `blocks`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Blocks of the message to send. This field is usually required, unless you're providing only `text` or `attachments`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Value>>,
+    /// A JSON-based array of structured attachments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<ChatPostMessageAttachment>>,
+}
+
+/// Slack incoming webhook, for posting messages without a token.
+///
+/// <https://api.slack.com/messaging/webhooks>
+#[derive(Clone)]
+pub struct Webhook {
+    /// URL of the incoming webhook.
+    pub url: String,
+    /// Reqwest client.
+    pub client: Client,
+    /// Tokio runtime used by the blocking methods. `None` for async-only webhooks.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub runtime: Option<Arc<Runtime>>,
+}
+
+impl Webhook {
+    /// Create a new incoming webhook posting to `url`.
+    ///
+    /// Without the `blocking` feature, no Tokio runtime is created.
+    pub fn new(url: String) -> Self {
+        Webhook {
+            url,
+            client: tls_client_builder().build().unwrap(),
+            #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+            runtime: Some(Arc::new(Runtime::new().unwrap())),
+        }
+    }
+
+    /// Create a new async-only incoming webhook that does not create a Tokio runtime.
+    pub fn new_async(url: String) -> Self {
+        Webhook {
+            url,
+            client: tls_client_builder().build().unwrap(),
+            #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+            runtime: None,
+        }
+    }
+
+    /// Sends a message to the incoming webhook.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub fn send(&self, payload: WebhookPayload) -> Result<(), SlackApiError> {
+        block_on(self.runtime.as_deref(), self.send_async(payload))
+    }
+
+    /// Sends a message to the incoming webhook asynchronously.
+    pub fn send_async(&self, payload: WebhookPayload) -> Pin<Box<dyn Future<Output=Result<(), SlackApiError>> + Send + '_>> {
+        if let Err(e) = validate_content(&payload.text, &payload.attachments, &payload.blocks) {
+            return Box::pin(async { Err(e) });
+        }
+
+        Box::pin(assert_send(async move {
+            let res = self.client.post(&self.url).json(&payload).send().await.map_err(|e| SlackApiError::from(e).in_method("webhook"))?;
+
+            // Webhooks answer with a plain text error code instead of a JSON body
+            let status = res.status().as_u16();
+            if !res.status().is_success() {
+                let error = res.text().await.map_err(|e| SlackApiError::from(e).in_method("webhook"))?;
+                return Err(SlackApiError::from_response("webhook", status, json!({ "ok": false, "error": error.trim() })));
+            }
+            Ok(())
+        }))
+    }
+}
+
+
+#[cfg(all(test, feature = "blocking", not(target_arch = "wasm32")))]
+mod webhook_tests {
+    use std::env;
+
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn webhook_send() {
+        let url = env::var("SLACK_WEBHOOK_URL").expect("SLACK_WEBHOOK_URL is not set");
+        let webhook = Webhook::new(url);
+
+        let payload = WebhookPayload {
+            text: Option::from("Hello, Slack from Rust!".to_string()),
+            ..Default::default()
+        };
+        let send = webhook.send(payload);
+        assert!(send.is_ok(), "Failed to send message");
+    }
+
+    #[test]
+    fn webhook_send_to_mock() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path("/services/T000/B000/XXXX"))
+                .and(body_json(json!({ "text": "Hello, Slack from Rust!" })))
+                .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+                .mount(&server)
+        );
+
+        let webhook = Webhook::new(format!("{}/services/T000/B000/XXXX", server.uri()));
+        let payload = WebhookPayload {
+            text: Option::from("Hello, Slack from Rust!".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(webhook.send(payload), Ok(()));
+    }
+
+    #[test]
+    fn webhook_surfaces_error_code() {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .respond_with(ResponseTemplate::new(400).set_body_string("invalid_payload"))
+                .mount(&server)
+        );
+
+        let webhook = Webhook::new(server.uri());
+        let payload = WebhookPayload {
+            text: Option::from("Hello, Slack from Rust!".to_string()),
+            ..Default::default()
+        };
+        let err = webhook.send(payload).unwrap_err();
+        assert_eq!(err.code(), Some("invalid_payload"));
+        assert_eq!(err.to_string(), "webhook: invalid_payload");
+    }
+
+    #[test]
+    fn webhook_requires_content() {
+        let webhook = Webhook::new("https://hooks.slack.com/services/T000/B000/XXXX".to_string());
+
+        // Sending an empty payload is rejected locally
+        let send = webhook.send(WebhookPayload::default());
+        assert!(matches!(send, Err(SlackApiError::InvalidArgument(_))), "Expected an invalid argument error");
+    }
+}